@@ -1,11 +1,14 @@
 // Copyright (c) Aptos
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::common::types::{CliCommand, CliTypedResult, TransactionOptions, TransactionSummary};
+use crate::common::types::{
+    AptAmount, CliCommand, CliError, CliTypedResult, TransactionOptions, TransactionSummary,
+};
 use aptos_types::account_address::AccountAddress;
 use async_trait::async_trait;
 use cached_packages::aptos_stdlib;
 use clap::Parser;
+use serde::Serialize;
 
 // TODO(Gas): double check if this is correct
 pub const DEFAULT_FUNDED_COINS: u64 = 50_000;
@@ -33,6 +36,63 @@ impl CliCommand<TransactionSummary> for CreateAccount {
         self.txn_options
             .submit_transaction(aptos_stdlib::aptos_account_create_account(address), None)
             .await
-            .map(TransactionSummary::from)
+            .map(|txn| self.txn_options.summarize(&txn))
+    }
+}
+
+/// Command to estimate the gas needed to create an account
+///
+/// Simulates creating an account at a random address, sent from the profile's account,
+/// at the node's current gas price estimate.
+#[derive(Debug, Parser)]
+pub struct EstimateAccountCreationGas {
+    #[clap(flatten)]
+    pub(crate) txn_options: TransactionOptions,
+}
+
+/// Estimated gas cost of a transaction
+#[derive(Debug, Serialize)]
+pub struct GasEstimate {
+    pub gas_used: u64,
+    pub gas_unit_price: u64,
+    /// Total cost, `gas_used` times `gas_unit_price`
+    pub cost: AptAmount,
+}
+
+#[async_trait]
+impl CliCommand<GasEstimate> for EstimateAccountCreationGas {
+    fn command_name(&self) -> &'static str {
+        "EstimateAccountCreationGas"
+    }
+
+    async fn execute(self) -> CliTypedResult<GasEstimate> {
+        let gas_unit_price =
+            if let Some(gas_unit_price) = self.txn_options.gas_options.gas_unit_price {
+                gas_unit_price
+            } else {
+                self.txn_options.estimate_gas_price().await?
+            };
+
+        let simulated_txn = self
+            .txn_options
+            .simulate_transaction(
+                aptos_stdlib::aptos_account_create_account(AccountAddress::random()),
+                Some(gas_unit_price),
+                None,
+            )
+            .await?;
+        if !simulated_txn.info.success {
+            return Err(CliError::ApiError(format!(
+                "Simulated transaction failed with status {}",
+                simulated_txn.info.vm_status
+            )));
+        }
+
+        let gas_used = simulated_txn.info.gas_used.0;
+        Ok(GasEstimate {
+            gas_used,
+            gas_unit_price,
+            cost: gas_used.saturating_mul(gas_unit_price).into(),
+        })
     }
 }
@@ -2,11 +2,10 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::common::types::{CliCommand, CliTypedResult, TransactionOptions, TransactionSummary};
-use aptos_rest_client::{
-    aptos_api_types::{WriteResource, WriteSetChange},
-    Transaction,
+use aptos_types::{
+    account_address::{create_resource_address, AccountAddress},
+    transaction::authenticator::AuthenticationKey,
 };
-use aptos_types::{account_address::AccountAddress, transaction::authenticator::AuthenticationKey};
 use async_trait::async_trait;
 use cached_packages::aptos_stdlib::resource_account_create_resource_account;
 use clap::Parser;
@@ -43,34 +42,6 @@ pub struct CreateResourceAccountSummary {
     pub transaction_summary: TransactionSummary,
 }
 
-impl From<Transaction> for CreateResourceAccountSummary {
-    fn from(transaction: Transaction) -> Self {
-        let transaction_summary = TransactionSummary::from(&transaction);
-
-        let mut summary = CreateResourceAccountSummary {
-            transaction_summary,
-            resource_account: None,
-        };
-
-        if let Transaction::UserTransaction(txn) = transaction {
-            summary.resource_account = txn.info.changes.iter().find_map(|change| match change {
-                WriteSetChange::WriteResource(WriteResource { address, data, .. }) => {
-                    if data.typ.name.as_str() == "Account"
-                        && *address.inner().to_hex() != *txn.request.sender.inner().to_hex()
-                    {
-                        Some(*address.inner())
-                    } else {
-                        None
-                    }
-                }
-                _ => None,
-            });
-        }
-
-        summary
-    }
-}
-
 #[async_trait]
 impl CliCommand<CreateResourceAccountSummary> for CreateResourceAccount {
     fn command_name(&self) -> &'static str {
@@ -83,15 +54,31 @@ impl CliCommand<CreateResourceAccountSummary> for CreateResourceAccount {
         } else {
             vec![]
         };
-        self.txn_options
+        let seed = bcs::to_bytes(&self.seed)?;
+
+        // The resource account address is deterministic, so derive it rather than inspecting
+        // the write set of the transaction
+        let resource_account = create_resource_address(self.txn_options.sender_address()?, &seed);
+        let transaction_summary = self
+            .txn_options
             .submit_transaction(
-                resource_account_create_resource_account(
-                    bcs::to_bytes(&self.seed)?,
-                    authentication_key,
-                ),
+                resource_account_create_resource_account(seed, authentication_key),
                 None,
             )
             .await
-            .map(CreateResourceAccountSummary::from)
+            .map(|txn| self.txn_options.summarize(&txn))?;
+
+        // A simulated transaction doesn't create the account
+        let resource_account = if transaction_summary.success == Some(true)
+            && transaction_summary.simulated.is_none()
+        {
+            Some(resource_account)
+        } else {
+            None
+        };
+        Ok(CreateResourceAccountSummary {
+            resource_account,
+            transaction_summary,
+        })
     }
 }
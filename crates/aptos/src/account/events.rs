@@ -0,0 +1,98 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::common::types::{CliCommand, CliError, CliTypedResult, ProfileOptions, RestOptions};
+use aptos_rest_client::{aptos_api_types::Event, Client, Transaction};
+use aptos_types::{account_address::AccountAddress, event::EventKey};
+use async_trait::async_trait;
+use clap::Parser;
+
+/// Command to show the events emitted to an event handle at a ledger version
+///
+/// The event handle is identified by the resource holding it and the name of
+/// the field, e.g. `0x1::coin::CoinStore<0x1::aptos_coin::AptosCoin>` and
+/// `deposit_events`.
+#[derive(Debug, Parser)]
+pub struct GetEventByVersion {
+    /// Address of the account holding the event handle
+    #[clap(long, parse(try_from_str=crate::common::types::load_account_arg))]
+    pub(crate) account: AccountAddress,
+
+    /// Struct tag of the resource holding the event handle
+    #[clap(long)]
+    pub(crate) event_handle_struct: String,
+
+    /// Name of the event handle field in the resource
+    #[clap(long)]
+    pub(crate) event_handle: String,
+
+    /// Ledger version of the transaction that emitted the event
+    #[clap(long)]
+    pub(crate) version: u64,
+
+    #[clap(flatten)]
+    pub(crate) rest_options: RestOptions,
+    #[clap(flatten)]
+    pub(crate) profile_options: ProfileOptions,
+}
+
+impl GetEventByVersion {
+    /// Looks up the key of the event handle from the account's resource
+    async fn event_key(&self, client: &Client) -> CliTypedResult<EventKey> {
+        let resource = client
+            .get_account_resource(self.account, &self.event_handle_struct)
+            .await?
+            .into_inner()
+            .ok_or_else(|| {
+                CliError::CommandArgumentError(format!(
+                    "Resource {} not found for account {}",
+                    self.event_handle_struct, self.account
+                ))
+            })?;
+
+        let id = &resource.data[&self.event_handle]["guid"]["id"];
+        let creation_number = id["creation_num"]
+            .as_str()
+            .and_then(|creation_number| creation_number.parse::<u64>().ok());
+        let address = id["addr"]
+            .as_str()
+            .and_then(|address| AccountAddress::from_hex_literal(address).ok());
+        match (creation_number, address) {
+            (Some(creation_number), Some(address)) => Ok(EventKey::new(creation_number, address)),
+            _ => Err(CliError::CommandArgumentError(format!(
+                "Field {} of {} is not an event handle",
+                self.event_handle, self.event_handle_struct
+            ))),
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand<Vec<Event>> for GetEventByVersion {
+    fn command_name(&self) -> &'static str {
+        "GetEventByVersion"
+    }
+
+    async fn execute(self) -> CliTypedResult<Vec<Event>> {
+        let client = self.rest_options.client(&self.profile_options.profile)?;
+        let event_key = self.event_key(&client).await?;
+
+        let events = match client
+            .get_transaction_by_version(self.version)
+            .await?
+            .into_inner()
+        {
+            Transaction::UserTransaction(txn) => txn.events,
+            Transaction::GenesisTransaction(txn) => txn.events,
+            Transaction::BlockMetadataTransaction(txn) => txn.events,
+            Transaction::PendingTransaction(_) | Transaction::StateCheckpointTransaction(_) => {
+                vec![]
+            }
+        };
+
+        Ok(events
+            .into_iter()
+            .filter(|event| event.key.0 == event_key)
+            .collect())
+    }
+}
@@ -0,0 +1,84 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::common::types::{
+    AptAmount, CliCommand, CliError, CliTypedResult, ProfileOptions, RestOptions,
+};
+use aptos_crypto::bls12381;
+use aptos_types::{
+    account_address::AccountAddress, account_config::CORE_CODE_ADDRESS,
+    network_address::NetworkAddress, on_chain_config::ValidatorSet, validator_info::ValidatorInfo,
+};
+use async_trait::async_trait;
+use clap::Parser;
+use serde::Serialize;
+
+/// Command to list the active validators and their voting power
+///
+/// Validators are sorted by voting power, largest first.
+#[derive(Debug, Parser)]
+pub struct GetValidators {
+    /// Only show this many validators with the most voting power
+    #[clap(long)]
+    pub(crate) top: Option<usize>,
+
+    #[clap(flatten)]
+    pub(crate) rest_options: RestOptions,
+    #[clap(flatten)]
+    pub(crate) profile_options: ProfileOptions,
+}
+
+/// An active validator and its voting power
+#[derive(Debug, Serialize)]
+pub struct ActiveValidator {
+    pub address: AccountAddress,
+    pub voting_power: AptAmount,
+    pub consensus_key: bls12381::PublicKey,
+    pub network_addresses: Vec<NetworkAddress>,
+    pub fullnode_addresses: Vec<NetworkAddress>,
+}
+
+impl TryFrom<&ValidatorInfo> for ActiveValidator {
+    type Error = CliError;
+
+    fn try_from(info: &ValidatorInfo) -> Result<Self, Self::Error> {
+        let config = info.config();
+        Ok(ActiveValidator {
+            address: info.account_address,
+            voting_power: info.consensus_voting_power().into(),
+            consensus_key: config.consensus_public_key.clone(),
+            network_addresses: config
+                .validator_network_addresses()
+                .map_err(|err| CliError::BCS("validator_network_addresses", err))?,
+            fullnode_addresses: config
+                .fullnode_network_addresses()
+                .map_err(|err| CliError::BCS("fullnode_network_addresses", err))?,
+        })
+    }
+}
+
+#[async_trait]
+impl CliCommand<Vec<ActiveValidator>> for GetValidators {
+    fn command_name(&self) -> &'static str {
+        "GetValidators"
+    }
+
+    async fn execute(self) -> CliTypedResult<Vec<ActiveValidator>> {
+        let client = self.rest_options.client(&self.profile_options.profile)?;
+        let validator_set: ValidatorSet = client
+            .get_account_resource_bcs(CORE_CODE_ADDRESS, "0x1::stake::ValidatorSet")
+            .await?
+            .into_inner();
+
+        let mut validators: Vec<&ValidatorInfo> = validator_set.active_validators.iter().collect();
+        validators.sort_by_key(|info| std::cmp::Reverse(info.consensus_voting_power()));
+        if let Some(top) = self.top {
+            validators.truncate(top);
+        }
+
+        validators
+            .into_iter()
+            .map(ActiveValidator::try_from)
+            .collect()
+    }
+}
@@ -1,25 +1,33 @@
 // Copyright (c) Aptos
 // SPDX-License-Identifier: Apache-2.0
 
-use std::{collections::BTreeMap, path::PathBuf, str::FromStr};
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::SystemTime,
+};
 
 use crate::common::{
     types::{
         CliCommand, CliConfig, CliError, CliTypedResult, ConfigSearchMode, EncodingOptions,
-        EncodingType, ExtractPublicKey, ParsePrivateKey, ProfileConfig, ProfileOptions,
-        PublicKeyInputOptions, RestOptions, RotationProofChallenge, TransactionOptions,
-        TransactionSummary,
+        EncodingType, ParsePrivateKey, PrivateKeyInputOptions, ProfileConfig, ProfileOptions,
+        PromptOptions, PublicKeyInputOptions, RestOptions, RotationProofChallenge, SaveFile,
+        TransactionOptions, TransactionSummary,
+    },
+    utils::{
+        create_dir_if_not_exist, prompt_yes_with_override, read_from_file, read_line, write_to_file,
     },
-    utils::{prompt_yes_with_override, read_line},
 };
 use aptos_crypto::{
-    ed25519::{Ed25519PrivateKey, Ed25519PublicKey},
+    ed25519::{Ed25519PrivateKey, Ed25519PublicKey, Ed25519Signature},
     PrivateKey, SigningKey,
 };
-use aptos_rest_client::Client;
+use aptos_rest_client::{aptos_api_types::HashValue, Client, Transaction};
 use aptos_types::{
-    account_address::AccountAddress, account_config::CORE_CODE_ADDRESS,
-    transaction::authenticator::AuthenticationKey,
+    account_address::AccountAddress,
+    account_config::CORE_CODE_ADDRESS,
+    transaction::{authenticator::AuthenticationKey, TransactionPayload},
 };
 use async_trait::async_trait;
 use cached_packages::aptos_stdlib;
@@ -37,12 +45,47 @@ pub struct RotateKey {
     #[clap(long, group = "private_key_to_rotate_to", parse(from_os_str))]
     pub(crate) new_private_key_file: Option<PathBuf>,
     /// New private key encoded in a type as shown in `encoding`
-    #[clap(long, group = "private_key_to_roate_to")]
+    #[clap(long, group = "private_key_to_rotate_to")]
     pub(crate) new_private_key: Option<String>,
+    /// File containing a BCS encoded `SignedRotationRequest` prepared in advance
+    ///
+    /// This is the output of `aptos account offline-sign-rotation`.  The rotation
+    /// proof must have been generated for the account's current sequence number.
+    #[clap(long, group = "private_key_to_rotate_to", parse(from_os_str))]
+    pub(crate) scheduled_rotation_file: Option<PathBuf>,
 
     /// Name of the profile to save the new private key
     #[clap(long)]
     pub(crate) save_to_profile: Option<String>,
+
+    /// Sign the rotation, but only simulate the transaction rather than submitting it
+    ///
+    /// This checks that the rotation would succeed on-chain with the current sequence
+    /// number, authentication key, and balance, and reports the gas it would use.
+    #[clap(long, group = "rotate_key_mode")]
+    pub(crate) simulate_only: bool,
+
+    /// Sign the rotation proof and verify the signatures locally, without submitting
+    ///
+    /// Shows the BCS encoded rotation proof challenge and both signatures in hex, to
+    /// help debug signature mismatches.
+    #[clap(long, group = "rotate_key_mode")]
+    pub(crate) dry_run: bool,
+
+    /// Check that the transaction emitted a `0x1::account::KeyRotationEvent` for the new key
+    #[clap(long)]
+    pub(crate) verify_event: bool,
+
+    /// File to write the JSON rotation summary to, for use in scripts
+    ///
+    /// Only a submitted rotation has a summary, so this can't be used with `--dry-run`,
+    /// `--simulate-only`, or `--simulate`
+    #[clap(
+        long,
+        parse(from_os_str),
+        conflicts_with_all = &["dry_run", "simulate_only", "simulate"]
+    )]
+    pub(crate) output_file: Option<PathBuf>,
 }
 
 impl ParsePrivateKey for RotateKey {}
@@ -59,30 +102,23 @@ impl RotateKey {
             self.new_private_key.clone(),
         )
     }
-}
-
-#[derive(Debug, Deserialize, Serialize)]
-pub struct RotateSummary {
-    message: Option<String>,
-    transaction: TransactionSummary,
-}
-
-#[async_trait]
-impl CliCommand<RotateSummary> for RotateKey {
-    fn command_name(&self) -> &'static str {
-        "RotateKey"
-    }
 
-    async fn execute(self) -> CliTypedResult<RotateSummary> {
-        let new_private_key = self
-            .extract_private_key(self.txn_options.encoding_options.encoding)?
+    /// Extract the new private key, failing if it wasn't provided
+    fn new_private_key(&self) -> CliTypedResult<Ed25519PrivateKey> {
+        self.extract_private_key(self.txn_options.encoding_options.encoding)?
             .ok_or_else(|| {
                 CliError::CommandArgumentError(
                     "One of ['--new-private-key', '--new-private-key-file'] must be used"
                         .to_string(),
                 )
-            })?;
+            })
+    }
 
+    /// Builds the rotation proof challenge from on-chain state, and signs it with both keys
+    async fn rotation_request(
+        &self,
+        new_private_key: &Ed25519PrivateKey,
+    ) -> CliTypedResult<SignedRotationRequest> {
         let sender_address = self.txn_options.sender_address()?;
 
         // Get sequence number for account
@@ -100,38 +136,176 @@ impl CliCommand<RotateSummary> for RotateKey {
             new_public_key: new_private_key.public_key().to_bytes().to_vec(),
         };
 
-        let rotation_msg =
-            bcs::to_bytes(&rotation_proof).map_err(|err| CliError::BCS("rotation_proof", err))?;
+        SignedRotationRequest::new(
+            rotation_proof,
+            &self.txn_options.private_key()?,
+            new_private_key,
+        )
+    }
+
+    /// Loads a rotation request signed in advance, and checks it can still be submitted
+    async fn scheduled_rotation_request(
+        &self,
+        scheduled_rotation_file: &Path,
+    ) -> CliTypedResult<SignedRotationRequest> {
+        let signed_rotation_request: SignedRotationRequest =
+            bcs::from_bytes(&read_from_file(scheduled_rotation_file)?)
+                .map_err(|err| CliError::BCS("signed_rotation_request", err))?;
+        let challenge = &signed_rotation_request.rotation_proof_challenge;
+        challenge.verify(
+            &signed_rotation_request.current_public_key,
+            &signed_rotation_request.rotation_proof_signed_by_current_private_key,
+            &signed_rotation_request.rotation_proof_signed_by_new_private_key,
+        )?;
 
-        // Signs the struct using both the current private key and the next private key
-        let rotation_proof_signed_by_current_private_key = self
+        let sender_address = self.txn_options.sender_address()?;
+        if challenge.originator != sender_address {
+            return Err(CliError::CommandArgumentError(format!(
+                "Scheduled rotation is for account {}, but the sender is {}",
+                challenge.originator, sender_address
+            )));
+        }
+
+        // Always check against the on-chain sequence number, ignoring `--sequence-number`
+        let client = self
             .txn_options
-            .private_key()?
-            .sign_arbitrary_message(&rotation_msg.clone());
-        let rotation_proof_signed_by_new_private_key =
-            new_private_key.sign_arbitrary_message(&rotation_msg);
+            .rest_options
+            .client(&self.txn_options.profile_options.profile)?;
+        let sequence_number = client
+            .get_account(sender_address)
+            .await?
+            .into_inner()
+            .sequence_number;
+        if challenge.sequence_number != sequence_number {
+            return Err(CliError::CommandArgumentError(format!(
+                "Scheduled rotation was signed for sequence number {}, but the account is at \
+                sequence number {}.  Please generate and sign a new rotation proof",
+                challenge.sequence_number, sequence_number
+            )));
+        }
 
-        let txn_summary = self
+        Ok(signed_rotation_request)
+    }
+
+    /// Checks the transaction emitted a `KeyRotationEvent` with the expected new auth key
+    async fn verify_key_rotation_event(
+        &self,
+        transaction_hash: HashValue,
+        expected_auth_key: AuthenticationKey,
+    ) -> CliTypedResult<()> {
+        let client = self
             .txn_options
-            .submit_transaction(
-                aptos_stdlib::account_rotate_authentication_key(
-                    0,
-                    // Existing public key
-                    self.txn_options
-                        .private_key()?
-                        .public_key()
-                        .to_bytes()
-                        .to_vec(),
-                    0,
-                    // New public key
-                    new_private_key.public_key().to_bytes().to_vec(),
-                    rotation_proof_signed_by_current_private_key
-                        .to_bytes()
-                        .to_vec(),
-                    rotation_proof_signed_by_new_private_key.to_bytes().to_vec(),
-                ),
+            .rest_options
+            .client(&self.txn_options.profile_options.profile)?;
+        let events = match client
+            .get_transaction_by_hash(transaction_hash.into())
+            .await?
+            .into_inner()
+        {
+            Transaction::UserTransaction(txn) => txn.events,
+            _ => vec![],
+        };
+
+        let event = events
+            .iter()
+            .find(|event| event.typ.to_string() == "0x1::account::KeyRotationEvent")
+            .ok_or_else(|| {
+                CliError::ApiError(format!(
+                    "No KeyRotationEvent found in transaction {}",
+                    transaction_hash
+                ))
+            })?;
+        check_key_rotation_event(&event.data, expected_auth_key)
+    }
+
+    /// Gets the signed rotation request, and the new private key if it was provided
+    async fn signed_rotation_request(
+        &self,
+    ) -> CliTypedResult<(SignedRotationRequest, Option<Ed25519PrivateKey>)> {
+        if let Some(ref scheduled_rotation_file) = self.scheduled_rotation_file {
+            Ok((
+                self.scheduled_rotation_request(scheduled_rotation_file)
+                    .await?,
                 None,
-            )
+            ))
+        } else {
+            let new_private_key = self.new_private_key()?;
+            Ok((
+                self.rotation_request(&new_private_key).await?,
+                Some(new_private_key),
+            ))
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RotateSummary {
+    pub message: Option<String>,
+    pub profile_name: Option<String>,
+    pub transaction: TransactionSummary,
+    /// Set when `--verify-event` was passed and the event matched
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_verified: Option<bool>,
+}
+
+/// Writes the summary to a temporary file first, so an interrupted write can't leave a
+/// partial summary at `path`
+fn write_rotate_summary(path: &Path, summary: &RotateSummary) -> CliTypedResult<()> {
+    let bytes = serde_json::to_vec_pretty(summary)
+        .map_err(|err| CliError::UnexpectedError(err.to_string()))?;
+    let mut temp_file_name = path.file_name().unwrap_or_default().to_os_string();
+    temp_file_name.push(".tmp");
+    let temp_path = path.with_file_name(temp_file_name);
+    write_to_file(&temp_path, "Rotation summary", &bytes)?;
+    std::fs::rename(&temp_path, path).map_err(|err| CliError::IO(path.display().to_string(), err))
+}
+
+#[async_trait]
+impl CliCommand<RotateSummary> for RotateKey {
+    fn command_name(&self) -> &'static str {
+        "RotateKey"
+    }
+
+    async fn execute(mut self) -> CliTypedResult<RotateSummary> {
+        let output_file = self.output_file.take();
+        let summary = self.rotate().await?;
+        if let Some(ref output_file) = output_file {
+            write_rotate_summary(output_file, &summary)?;
+        }
+        Ok(summary)
+    }
+}
+
+/// Checks the `new_authentication_key` of a `KeyRotationEvent`'s data matches the expected key
+///
+/// The key is a `vector<u8>`, so the API renders it as a `0x` prefixed hex string
+pub(crate) fn check_key_rotation_event(
+    event_data: &serde_json::Value,
+    expected_auth_key: AuthenticationKey,
+) -> CliTypedResult<()> {
+    let new_auth_key = event_data["new_authentication_key"].as_str();
+    let matches = new_auth_key
+        .and_then(|str| hex::decode(str.strip_prefix("0x").unwrap_or(str)).ok())
+        .map_or(false, |bytes| bytes == expected_auth_key.to_vec());
+    if !matches {
+        return Err(CliError::ApiError(format!(
+            "KeyRotationEvent has new authentication key {:?}, expected 0x{}",
+            new_auth_key, expected_auth_key
+        )));
+    }
+
+    Ok(())
+}
+
+impl RotateKey {
+    /// Submits the rotation, and optionally saves the new key to a profile
+    async fn rotate(self) -> CliTypedResult<RotateSummary> {
+        let sender_address = self.txn_options.sender_address()?;
+        let (signed_rotation_request, new_private_key) = self.signed_rotation_request().await?;
+
+        let txn_summary = self
+            .txn_options
+            .submit_transaction(signed_rotation_request.payload(), None)
             .await
             .map(TransactionSummary::from)?;
 
@@ -152,6 +326,41 @@ impl CliCommand<RotateSummary> for RotateKey {
             ));
         }
 
+        let transaction_hash = txn_summary.transaction_hash;
+        let new_auth_key = signed_rotation_request.new_auth_key()?;
+
+        // The rotation has committed at this point, so the new key is saved before verifying the
+        // event, to not lock the user out if verification fails
+        let mut summary = self.save_new_key(sender_address, txn_summary, new_private_key)?;
+
+        if self.verify_event {
+            self.verify_key_rotation_event(transaction_hash, new_auth_key)
+                .await?;
+            summary.event_verified = Some(true);
+        }
+
+        Ok(summary)
+    }
+
+    /// Optionally saves the new private key to a profile once the rotation has committed
+    fn save_new_key(
+        &self,
+        sender_address: AccountAddress,
+        txn_summary: TransactionSummary,
+        new_private_key: Option<Ed25519PrivateKey>,
+    ) -> CliTypedResult<RotateSummary> {
+        // A scheduled rotation only carries the new public key, so there's nothing to save
+        let new_private_key = if let Some(new_private_key) = new_private_key {
+            new_private_key
+        } else {
+            return Ok(RotateSummary {
+                transaction: txn_summary,
+                message: None,
+                profile_name: None,
+                event_verified: None,
+            });
+        };
+
         let mut profile_name: String;
 
         if self.save_to_profile.is_none() {
@@ -164,6 +373,8 @@ impl CliCommand<RotateSummary> for RotateKey {
                         return Ok(RotateSummary {
                             transaction: txn_summary,
                             message: None,
+                            profile_name: None,
+                            event_verified: None,
                         });
                     }
                     _ => {
@@ -176,7 +387,7 @@ impl CliCommand<RotateSummary> for RotateKey {
             profile_name = read_line("Profile name")?.trim().to_string();
         } else {
             // We can safely unwrap here
-            profile_name = self.save_to_profile.unwrap();
+            profile_name = self.save_to_profile.clone().unwrap();
         }
 
         // Check if profile name exists
@@ -197,6 +408,8 @@ impl CliCommand<RotateSummary> for RotateKey {
                             return Ok(RotateSummary {
                                 transaction: txn_summary,
                                 message: None,
+                                profile_name: None,
+                                event_verified: None,
                             });
                         }
                         _ => {
@@ -221,8 +434,8 @@ impl CliCommand<RotateSummary> for RotateKey {
             ..self.txn_options.profile_options.profile()?
         };
 
-        if let Some(url) = self.txn_options.rest_options.url {
-            profile_config.rest_url = Some(url.into());
+        if let Some(ref url) = self.txn_options.rest_options.url {
+            profile_config.rest_url = Some(url.to_string());
         }
 
         if config.profiles.is_none() {
@@ -241,10 +454,261 @@ impl CliCommand<RotateSummary> for RotateKey {
         Ok(RotateSummary {
             transaction: txn_summary,
             message: Some(format!("Profile {} is saved.", profile_name)),
+            profile_name: Some(profile_name),
+            event_verified: None,
         })
     }
 }
 
+/// Runs a `RotateKey` against the simulation endpoint, used for `--simulate-only`
+pub struct SimulateRotateKey(pub RotateKey);
+
+/// The simulated outcome of a key rotation
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SimulatedRotateSummary {
+    pub gas_used: u64,
+    pub gas_unit_price: u64,
+    pub success: bool,
+    pub vm_status: String,
+}
+
+#[async_trait]
+impl CliCommand<SimulatedRotateSummary> for SimulateRotateKey {
+    fn command_name(&self) -> &'static str {
+        "SimulateRotateKey"
+    }
+
+    async fn execute(self) -> CliTypedResult<SimulatedRotateSummary> {
+        let rotate_key = self.0;
+        let (signed_rotation_request, _) = rotate_key.signed_rotation_request().await?;
+
+        let simulated_txn = rotate_key
+            .txn_options
+            .simulate_transaction(
+                signed_rotation_request.payload(),
+                rotate_key.txn_options.gas_options.gas_unit_price,
+                None,
+            )
+            .await?;
+
+        Ok(SimulatedRotateSummary {
+            gas_used: simulated_txn.info.gas_used.0,
+            gas_unit_price: simulated_txn.request.gas_unit_price.0,
+            success: simulated_txn.info.success,
+            vm_status: simulated_txn.info.vm_status,
+        })
+    }
+}
+
+/// Signs and verifies a `RotateKey` rotation proof locally, used for `--dry-run`
+pub struct DryRunRotateKey(pub RotateKey);
+
+/// The signed rotation proof, encoded the way it is submitted on-chain
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RotateKeyDryRun {
+    pub rotation_proof_challenge: String,
+    pub current_public_key: String,
+    pub new_public_key: String,
+    pub rotation_proof_signed_by_current_private_key: String,
+    pub rotation_proof_signed_by_new_private_key: String,
+}
+
+#[async_trait]
+impl CliCommand<RotateKeyDryRun> for DryRunRotateKey {
+    fn command_name(&self) -> &'static str {
+        "DryRunRotateKey"
+    }
+
+    async fn execute(self) -> CliTypedResult<RotateKeyDryRun> {
+        let (signed_rotation_request, _) = self.0.signed_rotation_request().await?;
+        let challenge = &signed_rotation_request.rotation_proof_challenge;
+        challenge.verify(
+            &signed_rotation_request.current_public_key,
+            &signed_rotation_request.rotation_proof_signed_by_current_private_key,
+            &signed_rotation_request.rotation_proof_signed_by_new_private_key,
+        )?;
+
+        Ok(RotateKeyDryRun {
+            rotation_proof_challenge: hex::encode(
+                bcs::to_bytes(challenge).map_err(|err| CliError::BCS("rotation_proof", err))?,
+            ),
+            current_public_key: hex::encode(signed_rotation_request.current_public_key.to_bytes()),
+            new_public_key: hex::encode(&challenge.new_public_key),
+            rotation_proof_signed_by_current_private_key: hex::encode(
+                signed_rotation_request
+                    .rotation_proof_signed_by_current_private_key
+                    .to_bytes(),
+            ),
+            rotation_proof_signed_by_new_private_key: hex::encode(
+                signed_rotation_request
+                    .rotation_proof_signed_by_new_private_key
+                    .to_bytes(),
+            ),
+        })
+    }
+}
+
+/// A `RotationProofChallenge` signed by both the current and the new private key
+///
+/// This contains everything needed to submit a key rotation transaction, so it can be
+/// produced on an offline machine and submitted later.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SignedRotationRequest {
+    pub rotation_proof_challenge: RotationProofChallenge,
+    pub current_public_key: Ed25519PublicKey,
+    pub rotation_proof_signed_by_current_private_key: Ed25519Signature,
+    pub rotation_proof_signed_by_new_private_key: Ed25519Signature,
+}
+
+impl SignedRotationRequest {
+    /// Signs the challenge with both the current private key and the new private key
+    pub fn new(
+        rotation_proof_challenge: RotationProofChallenge,
+        current_private_key: &Ed25519PrivateKey,
+        new_private_key: &Ed25519PrivateKey,
+    ) -> CliTypedResult<Self> {
+        let rotation_msg = bcs::to_bytes(&rotation_proof_challenge)
+            .map_err(|err| CliError::BCS("rotation_proof", err))?;
+
+        Ok(SignedRotationRequest {
+            rotation_proof_signed_by_current_private_key: current_private_key
+                .sign_arbitrary_message(&rotation_msg),
+            rotation_proof_signed_by_new_private_key: new_private_key
+                .sign_arbitrary_message(&rotation_msg),
+            current_public_key: current_private_key.public_key(),
+            rotation_proof_challenge,
+        })
+    }
+
+    /// The authentication key the account will have after the rotation
+    pub fn new_auth_key(&self) -> CliTypedResult<AuthenticationKey> {
+        let new_public_key =
+            Ed25519PublicKey::try_from(self.rotation_proof_challenge.new_public_key.as_slice())
+                .map_err(|err| CliError::UnableToParse("new_public_key", err.to_string()))?;
+        Ok(AuthenticationKey::ed25519(&new_public_key))
+    }
+
+    /// Builds the `0x1::account::rotate_authentication_key` payload
+    pub fn payload(&self) -> TransactionPayload {
+        aptos_stdlib::account_rotate_authentication_key(
+            0,
+            // Existing public key
+            self.current_public_key.to_bytes().to_vec(),
+            0,
+            // New public key
+            self.rotation_proof_challenge.new_public_key.clone(),
+            self.rotation_proof_signed_by_current_private_key
+                .to_bytes()
+                .to_vec(),
+            self.rotation_proof_signed_by_new_private_key
+                .to_bytes()
+                .to_vec(),
+        )
+    }
+}
+
+/// Command to sign a key rotation proof challenge without network access
+///
+/// Reads a `RotationProofChallenge` from a file, signs it with both the current
+/// and the new private key, and writes the BCS encoded `SignedRotationRequest` to the output
+/// file.  No calls are made to the REST API, so this can be run on an air-gapped machine.
+#[derive(Debug, Parser)]
+pub struct OfflineSignRotation {
+    /// File containing the BCS encoded `RotationProofChallenge`
+    ///
+    /// Either the raw BCS bytes, or the hex encoded `rotation_proof_challenge` output by
+    /// `aptos account rotate-key --dry-run`
+    #[clap(long, parse(from_os_str))]
+    pub(crate) rotation_proof_file: PathBuf,
+
+    #[clap(flatten)]
+    pub(crate) private_key_options: PrivateKeyInputOptions,
+    #[clap(flatten)]
+    pub(crate) encoding_options: EncodingOptions,
+    #[clap(flatten)]
+    pub(crate) profile_options: ProfileOptions,
+
+    /// File name that contains the new private key
+    #[clap(long, group = "private_key_to_rotate_to", parse(from_os_str))]
+    pub(crate) new_private_key_file: Option<PathBuf>,
+    /// New private key encoded in a type as shown in `encoding`
+    #[clap(long, group = "private_key_to_rotate_to")]
+    pub(crate) new_private_key: Option<String>,
+
+    #[clap(flatten)]
+    pub(crate) save_file: SaveFile,
+}
+
+impl ParsePrivateKey for OfflineSignRotation {}
+
+/// Parses a `RotationProofChallenge` from either hex encoded or raw BCS bytes
+pub(crate) fn parse_rotation_proof(bytes: &[u8]) -> CliTypedResult<RotationProofChallenge> {
+    let hex_bytes = std::str::from_utf8(bytes).ok().and_then(|str| {
+        let str = str.trim();
+        hex::decode(str.strip_prefix("0x").unwrap_or(str)).ok()
+    });
+    bcs::from_bytes(hex_bytes.as_deref().unwrap_or(bytes))
+        .map_err(|err| CliError::BCS("rotation_proof", err))
+}
+
+#[async_trait]
+impl CliCommand<SignedRotationRequest> for OfflineSignRotation {
+    fn command_name(&self) -> &'static str {
+        "OfflineSignRotation"
+    }
+
+    async fn execute(self) -> CliTypedResult<SignedRotationRequest> {
+        self.save_file.check_file()?;
+
+        let encoding = self.encoding_options.encoding;
+        let current_private_key = self
+            .private_key_options
+            .extract_private_key(encoding, &self.profile_options.profile)?;
+        let new_private_key = self
+            .parse_private_key(
+                encoding,
+                self.new_private_key_file.clone(),
+                self.new_private_key.clone(),
+            )?
+            .ok_or_else(|| {
+                CliError::CommandArgumentError(
+                    "One of ['--new-private-key', '--new-private-key-file'] must be used"
+                        .to_string(),
+                )
+            })?;
+
+        let rotation_proof =
+            parse_rotation_proof(&read_from_file(self.rotation_proof_file.as_path())?)?;
+
+        // Catch mismatched keys here, rather than when the transaction is submitted
+        let current_auth_key = AuthenticationKey::ed25519(&current_private_key.public_key());
+        if rotation_proof.current_auth_key
+            != AccountAddress::from_bytes(&current_auth_key)
+                .map_err(|err| CliError::UnableToParse("auth_key", err.to_string()))?
+        {
+            return Err(CliError::CommandArgumentError(
+                "Current private key does not match the current auth key of the challenge"
+                    .to_string(),
+            ));
+        }
+        if rotation_proof.new_public_key != new_private_key.public_key().to_bytes() {
+            return Err(CliError::CommandArgumentError(
+                "New private key does not match the new public key of the challenge".to_string(),
+            ));
+        }
+
+        let signed_rotation_request =
+            SignedRotationRequest::new(rotation_proof, &current_private_key, &new_private_key)?;
+        self.save_file.save_to_file(
+            "Signed rotation request",
+            &bcs::to_bytes(&signed_rotation_request)
+                .map_err(|err| CliError::BCS("signed_rotation_request", err))?,
+        )?;
+
+        Ok(signed_rotation_request)
+    }
+}
+
 /// Command to lookup the account adress through on-chain lookup table
 ///
 #[derive(Debug, Parser)]
@@ -260,11 +724,29 @@ pub struct LookupAddress {
 
     #[clap(flatten)]
     pub(crate) rest_options: RestOptions,
+
+    /// Reuse a previous lookup of the same key if it is at most this many seconds old
+    ///
+    /// Results are cached in `.aptos/lookup_cache.json`.  Without this, the address is
+    /// always looked up on-chain
+    #[clap(long)]
+    pub(crate) cache_ttl_seconds: Option<u64>,
+}
+
+/// File in the config folder holding recent `LookupAddress` results
+const LOOKUP_CACHE_FILE: &str = "lookup_cache.json";
+
+/// A `LookupAddress` result, keyed by authentication key in the cache
+#[derive(Debug, Deserialize, Serialize)]
+struct CachedLookup {
+    account_address: AccountAddress,
+    rest_url: String,
+    fetched_at_unix_secs: u64,
 }
 
 impl LookupAddress {
-    pub(crate) fn public_key(&self) -> CliTypedResult<Ed25519PublicKey> {
-        self.public_key_options.extract_public_key(
+    pub(crate) fn auth_key(&self) -> CliTypedResult<AuthenticationKey> {
+        self.public_key_options.extract_auth_key(
             self.encoding_options.encoding,
             &self.profile_options.profile,
         )
@@ -274,15 +756,9 @@ impl LookupAddress {
     fn rest_client(&self) -> CliTypedResult<Client> {
         self.rest_options.client(&self.profile_options.profile)
     }
-}
 
-#[async_trait]
-impl CliCommand<AccountAddress> for LookupAddress {
-    fn command_name(&self) -> &'static str {
-        "LookupAddress"
-    }
-
-    async fn execute(self) -> CliTypedResult<AccountAddress> {
+    /// Looks up the original account address of the authentication key on-chain
+    async fn lookup(&self, auth_key: AuthenticationKey) -> CliTypedResult<AccountAddress> {
         let originating_resource = self
             .rest_client()?
             .get_account_resource(CORE_CODE_ADDRESS, "0x1::account::OriginatingAddress")
@@ -299,7 +775,7 @@ impl CliCommand<AccountAddress> for LookupAddress {
             })?;
 
         // The derived address that can be used to look up the original address
-        let address_key = AuthenticationKey::ed25519(&self.public_key()?).derived_address();
+        let address_key = auth_key.derived_address();
 
         Ok(AccountAddress::from_hex_literal(
             self.rest_client()?
@@ -321,3 +797,222 @@ impl CliCommand<AccountAddress> for LookupAddress {
         .map_err(|err| CliError::UnableToParse("AccountAddress", err.to_string()))?)
     }
 }
+
+#[async_trait]
+impl CliCommand<AccountAddress> for LookupAddress {
+    fn command_name(&self) -> &'static str {
+        "LookupAddress"
+    }
+
+    async fn execute(self) -> CliTypedResult<AccountAddress> {
+        let auth_key = self.auth_key()?;
+        let cache_ttl_seconds = if let Some(cache_ttl_seconds) = self.cache_ttl_seconds {
+            cache_ttl_seconds
+        } else {
+            return self.lookup(auth_key).await;
+        };
+
+        let aptos_folder = CliConfig::aptos_folder(ConfigSearchMode::CurrentDirAndParents)?;
+        let cache_file = aptos_folder.join(LOOKUP_CACHE_FILE);
+        // A missing or unreadable cache is treated as empty, and rewritten below
+        let mut cache: BTreeMap<String, CachedLookup> = if cache_file.exists() {
+            serde_json::from_slice(&read_from_file(&cache_file)?).unwrap_or_default()
+        } else {
+            BTreeMap::new()
+        };
+
+        let rest_url = self
+            .rest_options
+            .url(&self.profile_options.profile)?
+            .to_string();
+        let now = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map_err(|e| CliError::UnexpectedError(e.to_string()))?
+            .as_secs();
+        let cache_key = auth_key.to_string();
+        if let Some(cached) = cache.get(&cache_key) {
+            if cached.rest_url == rest_url
+                && now.saturating_sub(cached.fetched_at_unix_secs) <= cache_ttl_seconds
+            {
+                return Ok(cached.account_address);
+            }
+        }
+
+        let account_address = self.lookup(auth_key).await?;
+        cache.insert(
+            cache_key,
+            CachedLookup {
+                account_address,
+                rest_url,
+                fetched_at_unix_secs: now,
+            },
+        );
+        create_dir_if_not_exist(aptos_folder.as_path())?;
+        write_to_file(
+            &cache_file,
+            LOOKUP_CACHE_FILE,
+            &serde_json::to_vec_pretty(&cache)
+                .map_err(|err| CliError::UnexpectedError(err.to_string()))?,
+        )?;
+
+        Ok(account_address)
+    }
+}
+
+/// Command to rotate the keys of several profiles in one go
+///
+/// The batch file is YAML or JSON, listing the profile to rotate and the file containing
+/// its new private key:
+///
+/// ```yaml
+/// - profile: validator-1
+///   new_private_key_file: validator-1.key
+/// - profile: validator-2
+///   new_private_key_file: validator-2.key
+/// ```
+///
+/// Rotations are submitted one after another.  A failed rotation doesn't stop the rest of
+/// the batch; all failures are listed in the report once every profile has been tried.
+/// Each successfully rotated profile is updated with its new key.
+#[derive(Debug, Parser)]
+pub struct RotateKeyBatch {
+    /// YAML or JSON file listing the profiles to rotate
+    #[clap(long, parse(from_os_str))]
+    pub(crate) batch_file: PathBuf,
+
+    /// File to write the JSON report of the rotations to
+    #[clap(long, parse(from_os_str))]
+    pub(crate) report_file: PathBuf,
+
+    #[clap(flatten)]
+    pub(crate) encoding_options: EncodingOptions,
+    #[clap(flatten)]
+    pub(crate) prompt_options: PromptOptions,
+}
+
+/// A single profile to rotate, as listed in the batch file
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RotateKeyBatchEntry {
+    pub profile: String,
+    pub new_private_key_file: PathBuf,
+}
+
+/// The outcome of rotating a single profile in a batch
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RotateKeyBatchResult {
+    pub profile: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction: Option<TransactionSummary>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl RotateKeyBatch {
+    /// Rotates the key of one profile, and saves the new key to the profile
+    async fn rotate(&self, entry: &RotateKeyBatchEntry) -> CliTypedResult<TransactionSummary> {
+        let rotate_key = RotateKey {
+            txn_options: TransactionOptions {
+                encoding_options: EncodingOptions {
+                    encoding: self.encoding_options.encoding,
+                },
+                profile_options: ProfileOptions {
+                    profile: entry.profile.clone(),
+                },
+                prompt_options: self.prompt_options,
+                ..Default::default()
+            },
+            new_private_key_file: Some(entry.new_private_key_file.clone()),
+            new_private_key: None,
+            scheduled_rotation_file: None,
+            save_to_profile: None,
+            simulate_only: false,
+            dry_run: false,
+            verify_event: false,
+            output_file: None,
+        };
+
+        let new_private_key = rotate_key.new_private_key()?;
+        let signed_rotation_request = rotate_key.rotation_request(&new_private_key).await?;
+        let txn_summary: TransactionSummary = rotate_key
+            .txn_options
+            .submit_transaction(signed_rotation_request.payload(), None)
+            .await?
+            .into();
+        if txn_summary.success != Some(true) {
+            return Err(CliError::ApiError(format!(
+                "Transaction {} was not executed successfully",
+                txn_summary.transaction_hash
+            )));
+        }
+
+        // Save as we go, so a later failure doesn't lose keys that were already rotated
+        let mut config = CliConfig::load(ConfigSearchMode::CurrentDirAndParents)?;
+        if let Some(profile) = config
+            .profiles
+            .as_mut()
+            .and_then(|profiles| profiles.get_mut(&entry.profile))
+        {
+            profile.public_key = Some(new_private_key.public_key());
+            profile.private_key = Some(new_private_key);
+        }
+        config.save()?;
+
+        Ok(txn_summary)
+    }
+}
+
+#[async_trait]
+impl CliCommand<Vec<RotateKeyBatchResult>> for RotateKeyBatch {
+    fn command_name(&self) -> &'static str {
+        "RotateKeyBatch"
+    }
+
+    async fn execute(self) -> CliTypedResult<Vec<RotateKeyBatchResult>> {
+        let entries: Vec<RotateKeyBatchEntry> =
+            serde_yaml::from_slice(&read_from_file(&self.batch_file)?)?;
+
+        let mut results = Vec::with_capacity(entries.len());
+        for entry in &entries {
+            let result = match self.rotate(entry).await {
+                Ok(transaction) => RotateKeyBatchResult {
+                    profile: entry.profile.clone(),
+                    transaction: Some(transaction),
+                    error: None,
+                },
+                Err(err) => {
+                    eprintln!(
+                        "Failed to rotate key for profile {}: {}",
+                        entry.profile, err
+                    );
+                    RotateKeyBatchResult {
+                        profile: entry.profile.clone(),
+                        transaction: None,
+                        error: Some(err.to_string()),
+                    }
+                }
+            };
+            results.push(result);
+        }
+
+        let report = serde_json::to_string_pretty(&results)
+            .map_err(|err| CliError::UnexpectedError(err.to_string()))?;
+        write_to_file(&self.report_file, "Rotation report", report.as_bytes())?;
+
+        let failed: Vec<&str> = results
+            .iter()
+            .filter(|result| result.error.is_some())
+            .map(|result| result.profile.as_str())
+            .collect();
+        if !failed.is_empty() {
+            return Err(CliError::UnexpectedError(format!(
+                "Failed to rotate keys for {} of {} profiles: {}.  See {} for details",
+                failed.len(),
+                results.len(),
+                failed.join(", "),
+                self.report_file.display()
+            )));
+        }
+
+        Ok(results)
+    }
+}
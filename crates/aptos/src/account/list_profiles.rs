@@ -0,0 +1,64 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::common::types::{
+    CliCommand, CliConfig, CliTypedResult, ConfigSearchMode, ProfileOptions,
+};
+use aptos_crypto::ed25519::Ed25519PublicKey;
+use aptos_types::account_address::AccountAddress;
+use async_trait::async_trait;
+use clap::Parser;
+use serde::Serialize;
+
+/// Command to list the names of the profiles in the CLI config
+#[derive(Debug, Parser)]
+pub struct ListProfiles {
+    /// Also show the account address and public key of each profile
+    ///
+    /// Private keys are never shown
+    #[clap(long)]
+    pub(crate) verbose: bool,
+}
+
+#[async_trait]
+impl CliCommand<Vec<String>> for ListProfiles {
+    fn command_name(&self) -> &'static str {
+        "ListProfiles"
+    }
+
+    async fn execute(self) -> CliTypedResult<Vec<String>> {
+        ProfileOptions::list_profiles()
+    }
+}
+
+/// Runs a `ListProfiles` with the public details of each profile, used for `--verbose`
+pub struct VerboseListProfiles(pub ListProfiles);
+
+/// The public details of a profile
+#[derive(Debug, Serialize)]
+pub struct ProfileListing {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account: Option<AccountAddress>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_key: Option<Ed25519PublicKey>,
+}
+
+#[async_trait]
+impl CliCommand<Vec<ProfileListing>> for VerboseListProfiles {
+    fn command_name(&self) -> &'static str {
+        "ListProfiles"
+    }
+
+    async fn execute(self) -> CliTypedResult<Vec<ProfileListing>> {
+        Ok(CliConfig::load(ConfigSearchMode::CurrentDirAndParents)?
+            .profiles_sorted_by_name()
+            .into_iter()
+            .map(|(name, profile)| ProfileListing {
+                name,
+                account: profile.account,
+                public_key: profile.public_key,
+            })
+            .collect())
+    }
+}
@@ -6,10 +6,20 @@ use clap::Subcommand;
 
 pub mod create;
 pub mod create_resource_account;
+pub mod events;
 pub mod fund;
+pub mod get_validators;
 pub mod key_rotation;
 pub mod list;
+pub mod list_profiles;
+pub mod query_table;
+pub mod show_auth_key_history;
+pub mod show_coin_info;
+pub mod show_modules;
+pub mod show_staking_info;
+pub mod show_transaction;
 pub mod transfer;
+pub mod watch_balance;
 
 /// Tool for interacting with accounts
 ///
@@ -19,11 +29,25 @@ pub mod transfer;
 pub enum AccountTool {
     Create(create::CreateAccount),
     CreateResourceAccount(create_resource_account::CreateResourceAccount),
+    EstimateAccountCreationGas(create::EstimateAccountCreationGas),
     FundWithFaucet(fund::FundWithFaucet),
+    GetEventByVersion(events::GetEventByVersion),
+    GetValidators(get_validators::GetValidators),
     List(list::ListAccount),
+    ListProfiles(list_profiles::ListProfiles),
     LookupAddress(key_rotation::LookupAddress),
+    OfflineSignRotation(key_rotation::OfflineSignRotation),
+    QueryTable(query_table::QueryTable),
     RotateKey(key_rotation::RotateKey),
+    RotateKeyBatch(key_rotation::RotateKeyBatch),
+    ShowAuthKeyHistory(show_auth_key_history::ShowAuthKeyHistory),
+    ShowCoinInfo(show_coin_info::ShowCoinInfo),
+    ShowModules(show_modules::ShowModules),
+    ShowStakingInfo(show_staking_info::ShowStakingInfo),
+    ShowTransaction(show_transaction::ShowTransaction),
+    ShowTransactionByVersion(show_transaction::ShowTransactionByVersion),
     Transfer(transfer::TransferCoins),
+    WatchBalance(watch_balance::WatchBalance),
 }
 
 impl AccountTool {
@@ -31,11 +55,45 @@ impl AccountTool {
         match self {
             AccountTool::Create(tool) => tool.execute_serialized().await,
             AccountTool::CreateResourceAccount(tool) => tool.execute_serialized().await,
+            AccountTool::EstimateAccountCreationGas(tool) => tool.execute_serialized().await,
             AccountTool::FundWithFaucet(tool) => tool.execute_serialized().await,
+            AccountTool::GetEventByVersion(tool) => tool.execute_serialized().await,
+            AccountTool::GetValidators(tool) => tool.execute_serialized().await,
             AccountTool::List(tool) => tool.execute_serialized().await,
+            AccountTool::ListProfiles(tool) => {
+                if tool.verbose {
+                    list_profiles::VerboseListProfiles(tool)
+                        .execute_serialized()
+                        .await
+                } else {
+                    tool.execute_serialized().await
+                }
+            }
             AccountTool::LookupAddress(tool) => tool.execute_serialized().await,
-            AccountTool::RotateKey(tool) => tool.execute_serialized().await,
+            AccountTool::OfflineSignRotation(tool) => tool.execute_serialized().await,
+            AccountTool::QueryTable(tool) => tool.execute_serialized().await,
+            AccountTool::RotateKey(tool) => {
+                if tool.dry_run {
+                    key_rotation::DryRunRotateKey(tool)
+                        .execute_serialized()
+                        .await
+                } else if tool.simulate_only || tool.txn_options.simulate {
+                    key_rotation::SimulateRotateKey(tool)
+                        .execute_serialized()
+                        .await
+                } else {
+                    tool.execute_serialized().await
+                }
+            }
+            AccountTool::RotateKeyBatch(tool) => tool.execute_serialized().await,
+            AccountTool::ShowAuthKeyHistory(tool) => tool.execute_serialized().await,
+            AccountTool::ShowCoinInfo(tool) => tool.execute_serialized().await,
+            AccountTool::ShowModules(tool) => tool.execute_serialized().await,
+            AccountTool::ShowStakingInfo(tool) => tool.execute_serialized().await,
+            AccountTool::ShowTransaction(tool) => tool.execute_serialized().await,
+            AccountTool::ShowTransactionByVersion(tool) => tool.execute_serialized().await,
             AccountTool::Transfer(tool) => tool.execute_serialized().await,
+            AccountTool::WatchBalance(tool) => tool.execute_serialized().await,
         }
     }
 }
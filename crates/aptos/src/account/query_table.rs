@@ -0,0 +1,60 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::common::types::{CliCommand, CliTypedResult, ProfileOptions, RestOptions};
+use aptos_rest_client::aptos_api_types::MoveType;
+use aptos_types::account_address::AccountAddress;
+use async_trait::async_trait;
+use clap::Parser;
+
+/// Command to look up an item in a Move table
+///
+/// The key is provided as JSON, in the same format that the REST API returns
+/// values of the key type.
+#[derive(Debug, Parser)]
+pub struct QueryTable {
+    /// Handle of the table, e.g. the `handle` field of a `0x1::table::Table`
+    #[clap(long, parse(try_from_str=AccountAddress::from_hex_literal))]
+    pub(crate) table_handle: AccountAddress,
+
+    /// Move type of the table's keys
+    ///
+    /// Example: `address` or `0x1::string::String`
+    #[clap(long)]
+    pub(crate) key_type: MoveType,
+
+    /// Move type of the table's values
+    #[clap(long)]
+    pub(crate) value_type: MoveType,
+
+    /// Key to look up as JSON
+    ///
+    /// Example: `"0x1"` for an address key or `"100"` for a u64 key
+    #[clap(long, parse(try_from_str = serde_json::from_str))]
+    pub(crate) key: serde_json::Value,
+
+    #[clap(flatten)]
+    pub(crate) rest_options: RestOptions,
+    #[clap(flatten)]
+    pub(crate) profile_options: ProfileOptions,
+}
+
+#[async_trait]
+impl CliCommand<serde_json::Value> for QueryTable {
+    fn command_name(&self) -> &'static str {
+        "QueryTable"
+    }
+
+    async fn execute(self) -> CliTypedResult<serde_json::Value> {
+        let client = self.rest_options.client(&self.profile_options.profile)?;
+        Ok(client
+            .get_table_item(
+                self.table_handle,
+                &self.key_type.to_string(),
+                &self.value_type.to_string(),
+                self.key,
+            )
+            .await?
+            .into_inner())
+    }
+}
@@ -0,0 +1,121 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::common::types::{
+    CliCommand, CliError, CliTypedResult, ProfileOptions, RestOptions, TransactionSummary,
+};
+use aptos_rest_client::aptos_api_types::HashValue;
+use aptos_types::account_address::AccountAddress;
+use async_trait::async_trait;
+use clap::Parser;
+use serde::Serialize;
+
+/// Number of events to request from the node at a time
+const EVENTS_PAGE_SIZE: u16 = 100;
+
+/// Command to show the authentication key rotations of an account
+///
+/// The history is rebuilt from the `0x1::account::KeyRotationEvent`s emitted to the
+/// account's `key_rotation_events` handle, oldest first.  The current authentication key
+/// is printed before the history.
+#[derive(Debug, Parser)]
+pub struct ShowAuthKeyHistory {
+    /// Address of the account
+    ///
+    /// Defaults to the account of the profile
+    #[clap(long, parse(try_from_str=crate::common::types::load_account_arg))]
+    pub(crate) account: Option<AccountAddress>,
+
+    #[clap(flatten)]
+    pub(crate) rest_options: RestOptions,
+    #[clap(flatten)]
+    pub(crate) profile_options: ProfileOptions,
+}
+
+/// A single rotation of an account's authentication key
+#[derive(Debug, Serialize)]
+pub struct AuthKeyHistoryEntry {
+    /// Sequence number of the rotation event
+    pub sequence_number: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp_us: Option<u64>,
+    pub old_auth_key: String,
+    pub new_auth_key: String,
+    pub transaction_hash: HashValue,
+}
+
+#[async_trait]
+impl CliCommand<Vec<AuthKeyHistoryEntry>> for ShowAuthKeyHistory {
+    fn command_name(&self) -> &'static str {
+        "ShowAuthKeyHistory"
+    }
+
+    async fn execute(self) -> CliTypedResult<Vec<AuthKeyHistoryEntry>> {
+        let account = if let Some(account) = self.account {
+            account
+        } else {
+            self.profile_options.account_address()?
+        };
+        let client = self.rest_options.client(&self.profile_options.profile)?;
+
+        let current_auth_key = client
+            .get_account(account)
+            .await?
+            .into_inner()
+            .authentication_key;
+        eprintln!("Current authentication key: {}", current_auth_key);
+
+        let mut events = Vec::new();
+        loop {
+            let page = client
+                .get_account_events(
+                    account,
+                    "0x1::account::Account",
+                    "key_rotation_events",
+                    Some(events.len() as u64),
+                    Some(EVENTS_PAGE_SIZE),
+                )
+                .await?
+                .into_inner();
+            let page_len = page.len();
+            events.extend(page);
+            if page_len < EVENTS_PAGE_SIZE as usize {
+                break;
+            }
+        }
+        events.sort_by_key(|event| event.sequence_number.0);
+
+        let mut history = Vec::with_capacity(events.len());
+        for event in events {
+            let auth_key = |field: &str| {
+                event.data[field]
+                    .as_str()
+                    .map(str::to_string)
+                    .ok_or_else(|| {
+                        CliError::UnexpectedError(format!(
+                            "KeyRotationEvent {} has no {}",
+                            event.sequence_number.0, field
+                        ))
+                    })
+            };
+            let old_auth_key = auth_key("old_authentication_key")?;
+            let new_auth_key = auth_key("new_authentication_key")?;
+
+            let transaction = TransactionSummary::from(
+                client
+                    .get_transaction_by_version(event.version.0)
+                    .await?
+                    .into_inner(),
+            );
+            history.push(AuthKeyHistoryEntry {
+                sequence_number: event.sequence_number.0,
+                timestamp_us: transaction.timestamp_us,
+                old_auth_key,
+                new_auth_key,
+                transaction_hash: transaction.transaction_hash,
+            });
+        }
+
+        Ok(history)
+    }
+}
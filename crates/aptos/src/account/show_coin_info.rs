@@ -0,0 +1,130 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::common::types::{CliCommand, CliError, CliTypedResult, ProfileOptions, RestOptions};
+use aptos_rest_client::{aptos_api_types::MoveType, Client};
+use aptos_types::account_address::AccountAddress;
+use async_trait::async_trait;
+use clap::Parser;
+use serde::Serialize;
+use std::str::FromStr;
+
+/// Command to show the `0x1::coin::CoinInfo` of a coin type
+///
+/// The `CoinInfo` is stored at the address of the coin type's module.
+#[derive(Debug, Parser)]
+pub struct ShowCoinInfo {
+    /// Coin type
+    ///
+    /// Example: `0x1::aptos_coin::AptosCoin`
+    #[clap(long)]
+    pub(crate) coin_type: MoveType,
+
+    #[clap(flatten)]
+    pub(crate) rest_options: RestOptions,
+    #[clap(flatten)]
+    pub(crate) profile_options: ProfileOptions,
+}
+
+/// Metadata and supply of a coin type
+#[derive(Debug, Serialize)]
+pub struct CoinInfo {
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+    /// Total supply, if the coin tracks it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supply: Option<String>,
+}
+
+#[async_trait]
+impl CliCommand<CoinInfo> for ShowCoinInfo {
+    fn command_name(&self) -> &'static str {
+        "ShowCoinInfo"
+    }
+
+    async fn execute(self) -> CliTypedResult<CoinInfo> {
+        let coin_address = if let MoveType::Struct(ref struct_tag) = self.coin_type {
+            *struct_tag.address.inner()
+        } else {
+            return Err(CliError::CommandArgumentError(
+                "--coin-type must be a struct type".to_string(),
+            ));
+        };
+
+        let client = self.rest_options.client(&self.profile_options.profile)?;
+        let coin_info = client
+            .get_account_resource(
+                coin_address,
+                &format!("0x1::coin::CoinInfo<{}>", self.coin_type),
+            )
+            .await?
+            .into_inner()
+            .ok_or_else(|| {
+                CliError::CommandArgumentError(format!(
+                    "No CoinInfo found for coin type {}",
+                    self.coin_type
+                ))
+            })?
+            .data;
+
+        let field_str = |field: &str| {
+            coin_info[field]
+                .as_str()
+                .map(|value| value.to_string())
+                .ok_or_else(|| {
+                    CliError::UnexpectedError(format!("Unable to parse CoinInfo {}", field))
+                })
+        };
+        let decimals = coin_info["decimals"]
+            .as_u64()
+            .and_then(|decimals| u8::try_from(decimals).ok())
+            .ok_or_else(|| {
+                CliError::UnexpectedError("Unable to parse CoinInfo decimals".to_string())
+            })?;
+
+        Ok(CoinInfo {
+            name: field_str("name")?,
+            symbol: field_str("symbol")?,
+            decimals,
+            supply: supply(&client, &coin_info["supply"]).await?,
+        })
+    }
+}
+
+/// Reads the value of the `Option<OptionalAggregator>` supply of a `CoinInfo`
+async fn supply(client: &Client, supply: &serde_json::Value) -> CliTypedResult<Option<String>> {
+    let optional_aggregator = match supply["vec"].get(0) {
+        Some(optional_aggregator) => optional_aggregator,
+        None => return Ok(None),
+    };
+
+    // Non-parallelizable supply is stored inline
+    if let Some(integer) = optional_aggregator["integer"]["vec"].get(0) {
+        return Ok(integer["value"].as_str().map(|value| value.to_string()));
+    }
+
+    // Parallelizable supply is stored in the aggregator's table
+    if let Some(aggregator) = optional_aggregator["aggregator"]["vec"].get(0) {
+        let parse_address = |field: &str| {
+            aggregator[field]
+                .as_str()
+                .and_then(|address| AccountAddress::from_str(address).ok())
+                .ok_or_else(|| {
+                    CliError::UnexpectedError(format!("Unable to parse aggregator {}", field))
+                })
+        };
+        let value = client
+            .get_table_item(
+                parse_address("handle")?,
+                "address",
+                "u128",
+                parse_address("key")?.to_hex_literal(),
+            )
+            .await?
+            .into_inner();
+        return Ok(value.as_str().map(|value| value.to_string()));
+    }
+
+    Ok(None)
+}
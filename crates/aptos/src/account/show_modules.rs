@@ -0,0 +1,95 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::common::types::{CliCommand, CliError, CliTypedResult, ProfileOptions, RestOptions};
+use aptos_rest_client::aptos_api_types::MoveModuleBytecode;
+use aptos_types::account_address::AccountAddress;
+use async_trait::async_trait;
+use clap::Parser;
+use serde::Serialize;
+
+/// Command to show the Move modules deployed at an address
+///
+/// This summarizes the friends, structs, and exposed functions of each module,
+/// based on the module ABIs provided by the REST API.
+#[derive(Debug, Parser)]
+pub struct ShowModules {
+    /// Address of the account you want to show modules for
+    ///
+    /// Defaults to the account of the profile
+    #[clap(long, parse(try_from_str=crate::common::types::load_account_arg))]
+    pub(crate) account: Option<AccountAddress>,
+
+    /// Only show the module with this name
+    #[clap(long)]
+    pub(crate) module_name: Option<String>,
+
+    #[clap(flatten)]
+    pub(crate) rest_options: RestOptions,
+    #[clap(flatten)]
+    pub(crate) profile_options: ProfileOptions,
+}
+
+/// A summary of a Move module's ABI
+#[derive(Debug, Serialize)]
+pub struct ModuleSummary {
+    pub address: AccountAddress,
+    pub module_name: String,
+    pub friends: Vec<String>,
+    pub structs: Vec<String>,
+    pub functions: Vec<String>,
+}
+
+impl TryFrom<MoveModuleBytecode> for ModuleSummary {
+    type Error = CliError;
+
+    fn try_from(module: MoveModuleBytecode) -> Result<Self, Self::Error> {
+        let abi = module
+            .try_parse_abi()?
+            .abi
+            .ok_or_else(|| CliError::UnexpectedError("Unable to parse module ABI".to_string()))?;
+
+        Ok(ModuleSummary {
+            address: *abi.address.inner(),
+            module_name: abi.name.to_string(),
+            friends: abi
+                .friends
+                .iter()
+                .map(|friend| friend.to_string())
+                .collect(),
+            structs: abi.structs.iter().map(|s| s.name.to_string()).collect(),
+            functions: abi
+                .exposed_functions
+                .iter()
+                .map(|function| function.name.to_string())
+                .collect(),
+        })
+    }
+}
+
+#[async_trait]
+impl CliCommand<Vec<ModuleSummary>> for ShowModules {
+    fn command_name(&self) -> &'static str {
+        "ShowModules"
+    }
+
+    async fn execute(self) -> CliTypedResult<Vec<ModuleSummary>> {
+        let account = if let Some(account) = self.account {
+            account
+        } else {
+            self.profile_options.account_address()?
+        };
+
+        let client = self.rest_options.client(&self.profile_options.profile)?;
+        let modules = if let Some(ref module_name) = self.module_name {
+            vec![client
+                .get_account_module(account, module_name)
+                .await?
+                .into_inner()]
+        } else {
+            client.get_account_modules(account).await?.into_inner()
+        };
+
+        modules.into_iter().map(ModuleSummary::try_from).collect()
+    }
+}
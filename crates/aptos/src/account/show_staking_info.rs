@@ -0,0 +1,76 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::common::types::{AptAmount, CliCommand, CliTypedResult, ProfileOptions, RestOptions};
+use aptos_types::{account_address::AccountAddress, stake_pool::StakePool};
+use async_trait::async_trait;
+use clap::Parser;
+use serde::Serialize;
+
+/// Command to show the stake pool of an account
+///
+/// Shows the stake in each state of the `0x1::stake::StakePool`, along with
+/// the operator and voter of the pool.
+#[derive(Debug, Parser)]
+pub struct ShowStakingInfo {
+    /// Address of the account that owns the stake pool
+    ///
+    /// Defaults to the account of the profile
+    #[clap(long, parse(try_from_str=crate::common::types::load_account_arg))]
+    pub(crate) account: Option<AccountAddress>,
+
+    #[clap(flatten)]
+    pub(crate) rest_options: RestOptions,
+    #[clap(flatten)]
+    pub(crate) profile_options: ProfileOptions,
+}
+
+/// Balances and roles of a stake pool
+#[derive(Debug, Serialize)]
+pub struct StakingInfo {
+    /// Active stake, locked until `locked_until_secs`
+    pub locked_balance: AptAmount,
+    /// Inactive stake, which can be withdrawn
+    pub unlocked_balance: AptAmount,
+    pub pending_active: AptAmount,
+    pub pending_inactive: AptAmount,
+    pub locked_until_secs: u64,
+    pub operator_address: AccountAddress,
+    pub voter_address: AccountAddress,
+}
+
+impl From<StakePool> for StakingInfo {
+    fn from(stake_pool: StakePool) -> Self {
+        StakingInfo {
+            locked_balance: stake_pool.active.into(),
+            unlocked_balance: stake_pool.inactive.into(),
+            pending_active: stake_pool.pending_active.into(),
+            pending_inactive: stake_pool.pending_inactive.into(),
+            locked_until_secs: stake_pool.locked_until_secs,
+            operator_address: stake_pool.operator_address,
+            voter_address: stake_pool.delegated_voter,
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand<StakingInfo> for ShowStakingInfo {
+    fn command_name(&self) -> &'static str {
+        "ShowStakingInfo"
+    }
+
+    async fn execute(self) -> CliTypedResult<StakingInfo> {
+        let account = if let Some(account) = self.account {
+            account
+        } else {
+            self.profile_options.account_address()?
+        };
+
+        let client = self.rest_options.client(&self.profile_options.profile)?;
+        let stake_pool: StakePool = client
+            .get_account_resource_bcs(account, "0x1::stake::StakePool")
+            .await?
+            .into_inner();
+        Ok(stake_pool.into())
+    }
+}
@@ -0,0 +1,175 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::common::types::{
+    CliCommand, CliError, CliTypedResult, ProfileOptions, RestOptions, TransactionSummary,
+};
+use aptos_rest_client::{
+    aptos_api_types::{Event, HashValue, TransactionPayload},
+    Transaction,
+};
+use async_trait::async_trait;
+use clap::Parser;
+use serde::Serialize;
+use std::time::{Duration, Instant};
+
+/// Command to show a transaction by its hash
+#[derive(Debug, Parser)]
+pub struct ShowTransaction {
+    /// Hash of the transaction
+    #[clap(long)]
+    pub(crate) hash: HashValue,
+
+    /// Keep checking every second until the transaction is no longer pending
+    #[clap(long)]
+    pub(crate) poll_until_committed: bool,
+
+    /// Maximum number of seconds to poll for with `--poll-until-committed`
+    #[clap(long, default_value_t = 30)]
+    pub(crate) timeout_secs: u64,
+
+    #[clap(flatten)]
+    pub(crate) rest_options: RestOptions,
+    #[clap(flatten)]
+    pub(crate) profile_options: ProfileOptions,
+}
+
+/// A transaction summary, along with its payload and events
+#[derive(Debug, Serialize)]
+pub struct TransactionDetails {
+    #[serde(flatten)]
+    pub summary: TransactionSummary,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payload: Option<TransactionPayload>,
+    pub events: Vec<Event>,
+}
+
+impl From<Transaction> for TransactionDetails {
+    fn from(transaction: Transaction) -> Self {
+        let summary = TransactionSummary::from(&transaction);
+        let (payload, events) = match transaction {
+            Transaction::PendingTransaction(txn) => (Some(txn.request.payload), vec![]),
+            Transaction::UserTransaction(txn) => (Some(txn.request.payload), txn.events),
+            Transaction::GenesisTransaction(txn) => (None, txn.events),
+            Transaction::BlockMetadataTransaction(txn) => (None, txn.events),
+            Transaction::StateCheckpointTransaction(_) => (None, vec![]),
+        };
+
+        TransactionDetails {
+            summary,
+            payload,
+            events,
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand<TransactionDetails> for ShowTransaction {
+    fn command_name(&self) -> &'static str {
+        "ShowTransaction"
+    }
+
+    async fn execute(self) -> CliTypedResult<TransactionDetails> {
+        let client = self.rest_options.client(&self.profile_options.profile)?;
+        let start = Instant::now();
+        let timeout = Duration::from_secs(self.timeout_secs);
+
+        loop {
+            let transaction = client
+                .get_transaction_by_hash(self.hash.into())
+                .await?
+                .into_inner();
+
+            if !self.poll_until_committed || !transaction.is_pending() {
+                return Ok(transaction.into());
+            }
+            if start.elapsed() >= timeout {
+                return Err(CliError::UnexpectedError(format!(
+                    "Transaction {} is still pending after {} seconds",
+                    self.hash, self.timeout_secs
+                )));
+            }
+            tokio::time::sleep(Duration::from_secs(1)).await;
+        }
+    }
+}
+
+/// Command to show transactions by their ledger version
+///
+/// Either a single transaction with `--version`, or all transactions from `--range-start`
+/// up to, but not including, `--range-end`
+#[derive(Debug, Parser)]
+pub struct ShowTransactionByVersion {
+    /// Ledger version of the transaction
+    #[clap(long, group = "version_input")]
+    pub(crate) version: Option<u64>,
+
+    /// First ledger version of a range of transactions
+    #[clap(long, group = "version_input")]
+    pub(crate) range_start: Option<u64>,
+
+    /// Ledger version to stop before, defaults to `--range-start` plus `--limit`
+    #[clap(long)]
+    pub(crate) range_end: Option<u64>,
+
+    /// Maximum number of transactions to show for a range
+    #[clap(long, default_value_t = 25)]
+    pub(crate) limit: u16,
+
+    #[clap(flatten)]
+    pub(crate) rest_options: RestOptions,
+    #[clap(flatten)]
+    pub(crate) profile_options: ProfileOptions,
+}
+
+#[async_trait]
+impl CliCommand<Vec<TransactionDetails>> for ShowTransactionByVersion {
+    fn command_name(&self) -> &'static str {
+        "ShowTransactionByVersion"
+    }
+
+    async fn execute(self) -> CliTypedResult<Vec<TransactionDetails>> {
+        let client = self.rest_options.client(&self.profile_options.profile)?;
+
+        if let Some(version) = self.version {
+            let transaction = client
+                .get_transaction_by_version(version)
+                .await?
+                .into_inner();
+            return Ok(vec![transaction.into()]);
+        }
+
+        let range_start = self.range_start.ok_or_else(|| {
+            CliError::CommandArgumentError(
+                "One of ['--version', '--range-start'] must be used".to_string(),
+            )
+        })?;
+        let range_end = self
+            .range_end
+            .unwrap_or_else(|| range_start.saturating_add(self.limit as u64));
+        if range_end <= range_start {
+            return Err(CliError::CommandArgumentError(
+                "--range-end must be greater than --range-start".to_string(),
+            ));
+        }
+        let count = std::cmp::min(range_end - range_start, self.limit as u64);
+
+        // The node may return fewer transactions than asked for, so keep paging
+        let mut transactions: Vec<TransactionDetails> = Vec::new();
+        while (transactions.len() as u64) < count {
+            let start = range_start + transactions.len() as u64;
+            let remaining = (count - transactions.len() as u64) as u16;
+            let page = client
+                .get_transactions(Some(start), Some(remaining))
+                .await?
+                .into_inner();
+            if page.is_empty() {
+                break;
+            }
+            transactions.extend(page.into_iter().map(TransactionDetails::from));
+        }
+        transactions.truncate(count as usize);
+
+        Ok(transactions)
+    }
+}
@@ -37,13 +37,18 @@ impl CliCommand<TransferSummary> for TransferCoins {
     }
 
     async fn execute(self) -> CliTypedResult<TransferSummary> {
-        self.txn_options
+        let mut summary = self
+            .txn_options
             .submit_transaction(
                 aptos_stdlib::aptos_coin_transfer(self.account, self.amount),
                 Some(self.amount),
             )
             .await
-            .map(TransferSummary::from)
+            .map(TransferSummary::from)?;
+        if self.txn_options.simulate {
+            summary.simulated = Some(true);
+        }
+        Ok(summary)
     }
 }
 
@@ -60,6 +65,9 @@ pub struct TransferSummary {
     pub version: u64,
     pub vm_status: String,
     pub transaction_hash: HashValue,
+    /// Set when the transfer was only simulated with `--simulate`, and not committed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub simulated: Option<bool>,
 }
 
 impl From<Transaction> for TransferSummary {
@@ -100,6 +108,7 @@ impl From<Transaction> for TransferSummary {
                 version,
                 vm_status,
                 transaction_hash,
+                simulated: None,
             }
         } else {
             panic!("Can't call From<Transaction> for a non UserTransaction")
@@ -0,0 +1,148 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::common::types::{CliCommand, CliError, CliTypedResult, ProfileOptions, RestOptions};
+use aptos_rest_client::{aptos_api_types::MoveType, Client};
+use aptos_types::account_address::AccountAddress;
+use async_trait::async_trait;
+use clap::Parser;
+use serde::Serialize;
+use std::time::Duration;
+
+/// Command to watch the coin balance of an account
+///
+/// Polls the balance, and prints a line each time it changes, until interrupted
+/// with Ctrl-C.  If an alert threshold is crossed, the command exits with an error.
+#[derive(Debug, Parser)]
+pub struct WatchBalance {
+    /// Address of the account to watch
+    ///
+    /// Defaults to the account of the profile
+    #[clap(long, parse(try_from_str=crate::common::types::load_account_arg))]
+    pub(crate) account: Option<AccountAddress>,
+
+    /// Coin type to watch the balance of
+    #[clap(long, default_value = "0x1::aptos_coin::AptosCoin")]
+    pub(crate) coin_type: MoveType,
+
+    /// Number of seconds between balance checks
+    #[clap(long, default_value_t = 10)]
+    pub(crate) poll_interval_secs: u64,
+
+    /// Exit with an error when the balance drops below this amount
+    #[clap(long)]
+    pub(crate) alert_below: Option<u64>,
+
+    /// Exit with an error when the balance rises above this amount
+    #[clap(long)]
+    pub(crate) alert_above: Option<u64>,
+
+    #[clap(flatten)]
+    pub(crate) rest_options: RestOptions,
+    #[clap(flatten)]
+    pub(crate) profile_options: ProfileOptions,
+}
+
+/// The last balance seen before the watch was stopped
+#[derive(Debug, Serialize)]
+pub struct WatchedBalance {
+    pub account: AccountAddress,
+    pub coin_type: String,
+    pub balance: u64,
+}
+
+impl WatchBalance {
+    /// Fetches the balance, which is 0 if the account doesn't hold the coin
+    async fn balance(&self, client: &Client, account: AccountAddress) -> CliTypedResult<u64> {
+        let coin_store = client
+            .get_account_resource(
+                account,
+                &format!("0x1::coin::CoinStore<{}>", self.coin_type),
+            )
+            .await?
+            .into_inner();
+
+        match coin_store {
+            Some(coin_store) => coin_store.data["coin"]["value"]
+                .as_str()
+                .and_then(|value| value.parse::<u64>().ok())
+                .ok_or_else(|| {
+                    CliError::UnexpectedError("Unable to parse coin balance".to_string())
+                }),
+            None => Ok(0),
+        }
+    }
+
+    fn check_alerts(&self, balance: u64) -> CliTypedResult<()> {
+        if let Some(alert_below) = self.alert_below {
+            if balance < alert_below {
+                return Err(CliError::UnexpectedError(format!(
+                    "Balance {} dropped below {}",
+                    balance, alert_below
+                )));
+            }
+        }
+        if let Some(alert_above) = self.alert_above {
+            if balance > alert_above {
+                return Err(CliError::UnexpectedError(format!(
+                    "Balance {} rose above {}",
+                    balance, alert_above
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl CliCommand<WatchedBalance> for WatchBalance {
+    fn command_name(&self) -> &'static str {
+        "WatchBalance"
+    }
+
+    async fn execute(self) -> CliTypedResult<WatchedBalance> {
+        if self.poll_interval_secs == 0 {
+            return Err(CliError::CommandArgumentError(
+                "--poll-interval-secs must be greater than 0".to_string(),
+            ));
+        }
+        let account = if let Some(account) = self.account {
+            account
+        } else {
+            self.profile_options.account_address()?
+        };
+        let client = self.rest_options.client(&self.profile_options.profile)?;
+
+        let mut balance = self.balance(&client, account).await?;
+        println!("Balance: {}", balance);
+        self.check_alerts(balance)?;
+
+        let mut interval = tokio::time::interval(Duration::from_secs(self.poll_interval_secs));
+        // The first tick completes immediately
+        interval.tick().await;
+        loop {
+            tokio::select! {
+                _ = interval.tick() => {
+                    let new_balance = self.balance(&client, account).await?;
+                    if new_balance != balance {
+                        println!(
+                            "Balance: {} -> {} ({:+})",
+                            balance,
+                            new_balance,
+                            new_balance as i128 - balance as i128
+                        );
+                        balance = new_balance;
+                        self.check_alerts(balance)?;
+                    }
+                }
+                _ = tokio::signal::ctrl_c() => {
+                    return Ok(WatchedBalance {
+                        account,
+                        coin_type: self.coin_type.to_string(),
+                        balance,
+                    });
+                }
+            }
+        }
+    }
+}
@@ -7,9 +7,9 @@ use crate::{
         init::{DEFAULT_FAUCET_URL, DEFAULT_REST_URL},
         utils::{
             chain_id, check_if_file_exists, create_dir_if_not_exist, dir_default_to_current,
-            get_auth_key, get_sequence_number, read_from_file, start_logger, to_common_result,
-            to_common_success_result, write_to_file, write_to_file_with_opts,
-            write_to_user_only_file,
+            get_auth_key, get_sequence_number, parse_signed_transaction, read_from_file,
+            start_logger, to_common_result, to_common_success_result, write_to_file,
+            write_to_file_with_opts, write_to_user_only_file,
         },
     },
     config::GlobalConfig,
@@ -18,15 +18,16 @@ use crate::{
 use aptos_crypto::ed25519::Ed25519Signature;
 use aptos_crypto::{
     ed25519::{Ed25519PrivateKey, Ed25519PublicKey},
-    x25519, PrivateKey, ValidCryptoMaterial, ValidCryptoMaterialStringExt,
+    x25519, PrivateKey, Signature, ValidCryptoMaterial, ValidCryptoMaterialStringExt,
 };
 use aptos_keygen::KeyGen;
 use aptos_rest_client::aptos_api_types::{HashValue, UserTransaction};
 use aptos_rest_client::error::RestError;
 use aptos_rest_client::{Client, Transaction};
 use aptos_sdk::{transaction_builder::TransactionFactory, types::LocalAccount};
-use aptos_types::transaction::{
-    authenticator::AuthenticationKey, SignedTransaction, TransactionPayload,
+use aptos_types::{
+    chain_id::ChainId,
+    transaction::{authenticator::AuthenticationKey, SignedTransaction, TransactionPayload},
 };
 use async_trait::async_trait;
 use clap::{ArgEnum, Parser};
@@ -178,7 +179,7 @@ const LEGACY_CONFIG_FILE: &str = "config.yml";
 pub const CONFIG_FOLDER: &str = ".aptos";
 
 /// An individual profile
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct ProfileConfig {
     /// Private key for commands.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -281,6 +282,15 @@ impl CliConfig {
         Ok(config.remove_profile(profile))
     }
 
+    /// Copies of all profiles, sorted by profile name
+    pub fn profiles_sorted_by_name(&self) -> Vec<(String, ProfileConfig)> {
+        self.profiles
+            .iter()
+            .flatten()
+            .map(|(name, profile)| (name.clone(), profile.clone()))
+            .collect()
+    }
+
     pub fn remove_profile(&mut self, profile: &str) -> Option<ProfileConfig> {
         if let Some(ref mut profiles) = self.profiles {
             profiles.remove(&profile.to_string())
@@ -313,7 +323,7 @@ impl CliConfig {
     }
 
     /// Finds the current directory's .aptos folder
-    fn aptos_folder(mode: ConfigSearchMode) -> CliTypedResult<PathBuf> {
+    pub(crate) fn aptos_folder(mode: ConfigSearchMode) -> CliTypedResult<PathBuf> {
         let global_config = GlobalConfig::load()?;
         global_config.get_config_location(mode)
     }
@@ -388,6 +398,15 @@ impl ProfileOptions {
 
         Err(CliError::ConfigNotFoundError(self.profile.clone()))
     }
+
+    /// Names of all profiles in the config, sorted by name
+    pub fn list_profiles() -> CliTypedResult<Vec<String>> {
+        Ok(CliConfig::load(ConfigSearchMode::CurrentDirAndParents)?
+            .profiles_sorted_by_name()
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect())
+    }
 }
 
 impl Default for ProfileOptions {
@@ -556,6 +575,31 @@ pub struct PublicKeyInputOptions {
     /// Public key encoded in a type as shown in `encoding`
     #[clap(long, group = "public_key_input")]
     public_key: Option<String>,
+    /// Hex encoded authentication key, for when the public key isn't known
+    #[clap(long, group = "public_key_input", alias = "auth-key-raw", parse(try_from_str = parse_auth_key))]
+    auth_key: Option<AuthenticationKey>,
+}
+
+/// Parses a hex encoded authentication key, with or without a `0x` prefix
+fn parse_auth_key(str: &str) -> anyhow::Result<AuthenticationKey> {
+    AuthenticationKey::from_str(str.strip_prefix("0x").unwrap_or(str))
+}
+
+impl PublicKeyInputOptions {
+    /// Extracts the authentication key, deriving it from the public key if it wasn't given
+    pub fn extract_auth_key(
+        &self,
+        encoding: EncodingType,
+        profile: &str,
+    ) -> CliTypedResult<AuthenticationKey> {
+        if let Some(auth_key) = self.auth_key {
+            Ok(auth_key)
+        } else {
+            Ok(AuthenticationKey::ed25519(
+                &self.extract_public_key(encoding, profile)?,
+            ))
+        }
+    }
 }
 
 impl ExtractPublicKey for PublicKeyInputOptions {
@@ -935,6 +979,25 @@ pub trait CliCommand<T: Serialize + Send>: Sized + Send {
     }
 }
 
+/// Number of octas in one APT
+pub const OCTAS_PER_APT: u64 = 100_000_000;
+
+/// An amount of `0x1::aptos_coin::AptosCoin`, shown both in octas and in APT
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct AptAmount {
+    pub octas: u64,
+    pub apt: String,
+}
+
+impl From<u64> for AptAmount {
+    fn from(octas: u64) -> Self {
+        AptAmount {
+            octas,
+            apt: format!("{}.{:08}", octas / OCTAS_PER_APT, octas % OCTAS_PER_APT),
+        }
+    }
+}
+
 /// A shortened transaction output
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct TransactionSummary {
@@ -957,6 +1020,9 @@ pub struct TransactionSummary {
     pub version: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub vm_status: Option<String>,
+    /// Set when the transaction was only simulated with `--simulate`, and not committed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub simulated: Option<bool>,
 }
 
 impl From<Transaction> for TransactionSummary {
@@ -978,6 +1044,7 @@ impl From<&Transaction> for TransactionSummary {
                 version: None,
                 vm_status: None,
                 timestamp_us: None,
+                simulated: None,
             },
             Transaction::UserTransaction(txn) => TransactionSummary {
                 transaction_hash: txn.info.hash,
@@ -989,6 +1056,7 @@ impl From<&Transaction> for TransactionSummary {
                 vm_status: Some(txn.info.vm_status.clone()),
                 sequence_number: Some(txn.request.sequence_number.0),
                 timestamp_us: Some(txn.timestamp.0),
+                simulated: None,
                 pending: None,
             },
             Transaction::GenesisTransaction(txn) => TransactionSummary {
@@ -1002,6 +1070,7 @@ impl From<&Transaction> for TransactionSummary {
                 pending: None,
                 sequence_number: None,
                 timestamp_us: None,
+                simulated: None,
             },
             Transaction::BlockMetadataTransaction(txn) => TransactionSummary {
                 transaction_hash: txn.info.hash,
@@ -1009,6 +1078,7 @@ impl From<&Transaction> for TransactionSummary {
                 version: Some(txn.info.version.0),
                 vm_status: Some(txn.info.vm_status.clone()),
                 timestamp_us: Some(txn.timestamp.0),
+                simulated: None,
                 sender: None,
                 gas_used: None,
                 gas_unit_price: None,
@@ -1021,6 +1091,7 @@ impl From<&Transaction> for TransactionSummary {
                 version: Some(txn.info.version.0),
                 vm_status: Some(txn.info.vm_status.clone()),
                 timestamp_us: Some(txn.timestamp.0),
+                simulated: None,
                 sender: None,
                 gas_used: None,
                 gas_unit_price: None,
@@ -1109,7 +1180,7 @@ pub struct GasOptions {
     pub max_gas: Option<u64>,
 }
 
-const DEFAULT_MAX_GAS: u64 = 50000;
+pub(crate) const DEFAULT_MAX_GAS: u64 = 50000;
 
 /// Common options for interacting with an account for a validator
 #[derive(Debug, Default, Parser)]
@@ -1121,6 +1192,34 @@ pub struct TransactionOptions {
     /// as the max gas
     #[clap(long)]
     pub(crate) estimate_max_gas: bool,
+    /// Only simulate the transaction, rather than submitting it
+    ///
+    /// Shows the gas the transaction would use, and its status, including any Move abort
+    #[clap(long)]
+    pub(crate) simulate: bool,
+    /// Sequence number to sign the transaction with
+    ///
+    /// Without a value, it will be looked up from the account.  Required when
+    /// only signing a transaction, as no connection to a node is made
+    #[clap(long)]
+    pub(crate) sequence_number: Option<u64>,
+    /// Chain ID to sign the transaction for
+    ///
+    /// Required when only signing a transaction, e.g. `testnet` or `4`.  Otherwise, it's
+    /// checked against the chain ID of the node the transaction is sent to
+    #[clap(long)]
+    pub(crate) chain_id: Option<ChainId>,
+    /// Hex encoded BCS signed transaction to submit, instead of signing one
+    ///
+    /// Its payload must match the transaction the command would otherwise sign, e.g. one
+    /// signed with `aptos move run --sign-only`
+    #[clap(
+        long,
+        group = "private_key_input",
+        conflicts_with = "simulate",
+        parse(try_from_str = parse_signed_transaction)
+    )]
+    pub(crate) pre_signed_txn: Option<SignedTransaction>,
     #[clap(flatten)]
     pub(crate) private_key_options: PrivateKeyInputOptions,
     #[clap(flatten)]
@@ -1173,17 +1272,53 @@ impl TransactionOptions {
         get_auth_key(&client, sender_address).await
     }
 
+    /// Gets the sequence number, preferring the one given with `--sequence-number`
     pub async fn sequence_number(&self, sender_address: AccountAddress) -> CliTypedResult<u64> {
+        if let Some(sequence_number) = self.sequence_number {
+            return Ok(sequence_number);
+        }
+
         let client = self.rest_client()?;
         get_sequence_number(&client, sender_address).await
     }
 
+    /// Gets the node's chain ID, checking it matches the one given with `--chain-id`
+    async fn chain_id(&self, client: &Client) -> CliTypedResult<ChainId> {
+        let node_chain_id = chain_id(client).await?;
+        match self.chain_id {
+            Some(expected_chain_id) if expected_chain_id != node_chain_id => {
+                Err(CliError::CommandArgumentError(format!(
+                    "--chain-id {} doesn't match the node's chain ID {}",
+                    expected_chain_id, node_chain_id
+                )))
+            }
+            _ => Ok(node_chain_id),
+        }
+    }
+
     /// Submit a transaction
     pub async fn submit_transaction(
         &self,
         payload: TransactionPayload,
         amount_transfer: Option<u64>,
     ) -> CliTypedResult<Transaction> {
+        if let Some(ref signed_transaction) = self.pre_signed_txn {
+            return self
+                .submit_pre_signed_transaction(signed_transaction, &payload)
+                .await;
+        }
+
+        if self.simulate {
+            let simulated_txn = self
+                .simulate_transaction(payload, self.gas_options.gas_unit_price, amount_transfer)
+                .await?;
+            eprintln!(
+                "Simulated transaction used {} gas units with status {}",
+                simulated_txn.info.gas_used.0, simulated_txn.info.vm_status
+            );
+            return Ok(Transaction::UserTransaction(Box::new(simulated_txn)));
+        }
+
         let sender_key = self.private_key()?;
         let client = self.rest_client()?;
 
@@ -1229,7 +1364,7 @@ impl TransactionOptions {
         }
 
         // Sign and submit transaction
-        let transaction_factory = TransactionFactory::new(chain_id(&client).await?)
+        let transaction_factory = TransactionFactory::new(self.chain_id(&client).await?)
             .with_gas_unit_price(gas_unit_price)
             .with_max_gas_amount(max_gas);
         let sender_account = &mut LocalAccount::new(sender_address, sender_key, sequence_number);
@@ -1243,6 +1378,77 @@ impl TransactionOptions {
         Ok(response.into_inner())
     }
 
+    /// Submits the transaction given with `--pre-signed-txn`
+    async fn submit_pre_signed_transaction(
+        &self,
+        signed_transaction: &SignedTransaction,
+        payload: &TransactionPayload,
+    ) -> CliTypedResult<Transaction> {
+        let sender_address = self.sender_address()?;
+        if signed_transaction.sender() != sender_address {
+            return Err(CliError::CommandArgumentError(format!(
+                "--pre-signed-txn is signed by {}, but the sender is {}",
+                signed_transaction.sender(),
+                sender_address
+            )));
+        }
+        if signed_transaction.payload() != payload {
+            return Err(CliError::CommandArgumentError(
+                "The payload of --pre-signed-txn doesn't match the transaction of this command"
+                    .to_string(),
+            ));
+        }
+
+        Ok(self
+            .rest_client()?
+            .submit_and_wait(signed_transaction)
+            .await?
+            .into_inner())
+    }
+
+    /// Summarizes a transaction returned by `submit_transaction`
+    ///
+    /// The summary is marked when the transaction was only simulated with `--simulate`
+    pub fn summarize(&self, transaction: &Transaction) -> TransactionSummary {
+        let mut summary = TransactionSummary::from(transaction);
+        if self.simulate {
+            summary.simulated = Some(true);
+        }
+        summary
+    }
+
+    /// Signs a transaction without submitting it
+    ///
+    /// No REST calls are made, so the sequence number, chain ID, and gas unit price
+    /// must be provided on the command line.
+    pub fn sign_only(&self, payload: TransactionPayload) -> CliTypedResult<SignedTransaction> {
+        let sender_key = self.private_key()?;
+        let sender_address = self.sender_address()?;
+
+        let sequence_number = self.sequence_number.ok_or_else(|| {
+            CliError::CommandArgumentError(
+                "--sequence-number is required when only signing a transaction".to_string(),
+            )
+        })?;
+        let chain_id = self.chain_id.ok_or_else(|| {
+            CliError::CommandArgumentError(
+                "--chain-id is required when only signing a transaction".to_string(),
+            )
+        })?;
+        let gas_unit_price = self.gas_options.gas_unit_price.ok_or_else(|| {
+            CliError::CommandArgumentError(
+                "--gas-unit-price is required when only signing a transaction".to_string(),
+            )
+        })?;
+        let max_gas = self.gas_options.max_gas.unwrap_or(DEFAULT_MAX_GAS);
+
+        let transaction_factory = TransactionFactory::new(chain_id)
+            .with_gas_unit_price(gas_unit_price)
+            .with_max_gas_amount(max_gas);
+        let sender_account = &mut LocalAccount::new(sender_address, sender_key, sequence_number);
+        Ok(sender_account.sign_with_transaction_builder(transaction_factory.payload(payload)))
+    }
+
     pub async fn simulate_transaction(
         &self,
         payload: TransactionPayload,
@@ -1256,7 +1462,7 @@ impl TransactionOptions {
         let sender_address = self.sender_address()?;
 
         // Get sequence number for account
-        let sequence_number = get_sequence_number(&client, sender_address).await?;
+        let sequence_number = self.sequence_number(sender_address).await?;
 
         // Estimate gas price if necessary
         let gas_price = if let Some(gas_price) = gas_price {
@@ -1290,7 +1496,7 @@ impl TransactionOptions {
             )
         };
 
-        let transaction_factory = TransactionFactory::new(chain_id(&client).await?)
+        let transaction_factory = TransactionFactory::new(self.chain_id(&client).await?)
             .with_gas_unit_price(gas_price)
             .with_max_gas_amount(max_possible_gas);
 
@@ -1343,7 +1549,7 @@ pub struct PoolAddressArgs {
 // Since the struct RotationProofChallenge is defined in "0x1::account::RotationProofChallenge",
 // we will be passing in "0x1" to `account_address`, "account" to `module_name`, and "RotationProofChallenge" to `struct_name`
 // Originator refers to the user's address
-#[derive(Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct RotationProofChallenge {
     // Should be `CORE_CODE_ADDRESS`
     pub account_address: AccountAddress,
@@ -1356,3 +1562,45 @@ pub struct RotationProofChallenge {
     pub current_auth_key: AccountAddress,
     pub new_public_key: Vec<u8>,
 }
+
+impl RotationProofChallenge {
+    /// Verifies that the challenge was signed by both the current key and the new key
+    pub fn verify(
+        &self,
+        current_public_key: &Ed25519PublicKey,
+        signed_by_current_private_key: &Ed25519Signature,
+        signed_by_new_private_key: &Ed25519Signature,
+    ) -> CliTypedResult<()> {
+        let current_auth_key = AuthenticationKey::ed25519(current_public_key);
+        if self.current_auth_key
+            != AccountAddress::from_bytes(&current_auth_key)
+                .map_err(|err| CliError::UnableToParse("auth_key", err.to_string()))?
+        {
+            return Err(CliError::CommandArgumentError(
+                "Current public key does not match the current auth key of the challenge"
+                    .to_string(),
+            ));
+        }
+        let new_public_key = Ed25519PublicKey::try_from(self.new_public_key.as_slice())
+            .map_err(|err| CliError::UnableToParse("new_public_key", err.to_string()))?;
+
+        let rotation_msg =
+            bcs::to_bytes(self).map_err(|err| CliError::BCS("rotation_proof", err))?;
+        signed_by_current_private_key
+            .verify_arbitrary_msg(&rotation_msg, current_public_key)
+            .map_err(|err| {
+                CliError::CommandArgumentError(format!(
+                    "Invalid signature by the current private key: {}",
+                    err
+                ))
+            })?;
+        signed_by_new_private_key
+            .verify_arbitrary_msg(&rotation_msg, &new_public_key)
+            .map_err(|err| {
+                CliError::CommandArgumentError(format!(
+                    "Invalid signature by the new private key: {}",
+                    err
+                ))
+            })
+    }
+}
@@ -9,7 +9,10 @@ use aptos_build_info::build_information;
 use aptos_crypto::HashValue;
 use aptos_logger::{debug, Level};
 use aptos_rest_client::{Account, Client};
-use aptos_types::{chain_id::ChainId, transaction::authenticator::AuthenticationKey};
+use aptos_types::{
+    chain_id::ChainId,
+    transaction::{authenticator::AuthenticationKey, SignedTransaction},
+};
 use itertools::Itertools;
 use move_deps::move_core_types::account_address::AccountAddress;
 use reqwest::Url;
@@ -349,3 +352,18 @@ pub fn start_logger() {
         .read_env();
     logger.build();
 }
+
+/// Parses a hex encoded BCS `SignedTransaction`, with or without a `0x` prefix
+///
+/// The signature is checked, to catch bad signatures before they get to the node
+pub fn parse_signed_transaction(str: &str) -> CliTypedResult<SignedTransaction> {
+    let str = str.trim();
+    let bytes = hex::decode(str.strip_prefix("0x").unwrap_or(str))
+        .map_err(|err| CliError::UnableToParse("SignedTransaction", err.to_string()))?;
+    let signed_transaction: SignedTransaction = bcs::from_bytes(&bytes)?;
+    signed_transaction
+        .clone()
+        .check_signature()
+        .map_err(|err| CliError::CommandArgumentError(format!("Invalid signature: {}", err)))?;
+    Ok(signed_transaction)
+}
@@ -2,14 +2,16 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::common::types::{
-    CliCommand, CliConfig, CliError, CliResult, CliTypedResult, ConfigSearchMode, ProfileSummary,
-    CONFIG_FOLDER,
+    CliCommand, CliConfig, CliError, CliResult, CliTypedResult, ConfigSearchMode, ProfileConfig,
+    ProfileSummary, PromptOptions, CONFIG_FOLDER,
 };
 use crate::common::utils::{
-    create_dir_if_not_exist, current_dir, read_from_file, write_to_user_only_file,
+    create_dir_if_not_exist, current_dir, prompt_yes_with_override, read_from_file,
+    write_to_user_only_file,
 };
 use crate::genesis::git::{from_yaml, to_yaml};
 use crate::Tool;
+use aptos_crypto::PrivateKey;
 use async_trait::async_trait;
 use clap::ArgEnum;
 use clap::CommandFactory;
@@ -30,6 +32,7 @@ use std::str::FromStr;
 pub enum ConfigTool {
     Init(crate::common::init::InitTool),
     GenerateShellCompletions(GenerateShellCompletions),
+    ImportProfileFromJson(ImportProfileFromJson),
     SetGlobalConfig(SetGlobalConfig),
     ShowGlobalConfig(ShowGlobalConfig),
     ShowProfiles(ShowProfiles),
@@ -40,6 +43,7 @@ impl ConfigTool {
         match self {
             ConfigTool::Init(tool) => tool.execute_serialized_success().await,
             ConfigTool::GenerateShellCompletions(tool) => tool.execute_serialized_success().await,
+            ConfigTool::ImportProfileFromJson(tool) => tool.execute_serialized().await,
             ConfigTool::SetGlobalConfig(tool) => tool.execute_serialized().await,
             ConfigTool::ShowGlobalConfig(tool) => tool.execute_serialized().await,
             ConfigTool::ShowProfiles(tool) => tool.execute_serialized().await,
@@ -53,7 +57,7 @@ impl ConfigTool {
 /// to install the completion file.
 #[derive(Parser)]
 pub struct GenerateShellCompletions {
-    /// Shell to generate completions for one of [bash, elvish, powershell, zsh]
+    /// Shell to generate completions for one of [bash, elvish, fish, powershell, zsh]
     #[clap(long)]
     shell: Shell,
 
@@ -131,8 +135,7 @@ impl CliCommand<BTreeMap<String, ProfileSummary>> for ShowProfiles {
         // Load the profile config
         let config = CliConfig::load(ConfigSearchMode::CurrentDir)?;
         Ok(config
-            .profiles
-            .unwrap_or_default()
+            .profiles_sorted_by_name()
             .into_iter()
             .filter(|(key, _)| {
                 if let Some(ref profile) = self.profile {
@@ -146,6 +149,75 @@ impl CliCommand<BTreeMap<String, ProfileSummary>> for ShowProfiles {
     }
 }
 
+/// Imports a profile from inline JSON
+///
+/// The JSON has the same fields as a profile in `.aptos/config.yaml`, so a profile
+/// shown by `aptos config show-profiles` can be imported, with its `private_key` added.
+#[derive(Parser, Debug)]
+pub struct ImportProfileFromJson {
+    /// Profile as JSON
+    ///
+    /// Example: `{"account": "0x1", "rest_url": "http://localhost:8080"}`
+    #[clap(long)]
+    json: String,
+
+    /// Name of the profile to import into
+    #[clap(long)]
+    profile_name: String,
+
+    #[clap(flatten)]
+    prompt_options: PromptOptions,
+}
+
+#[async_trait]
+impl CliCommand<ProfileSummary> for ImportProfileFromJson {
+    fn command_name(&self) -> &'static str {
+        "ImportProfileFromJson"
+    }
+
+    async fn execute(self) -> CliTypedResult<ProfileSummary> {
+        // Keys and addresses are checked as part of deserialization
+        let profile: ProfileConfig = serde_json::from_str(&self.json)
+            .map_err(|err| CliError::UnableToParse("--json", err.to_string()))?;
+
+        for url in [&profile.rest_url, &profile.faucet_url]
+            .into_iter()
+            .flatten()
+        {
+            reqwest::Url::parse(url)
+                .map_err(|err| CliError::UnableToParse("--json", format!("{}: {}", url, err)))?;
+        }
+        if let (Some(private_key), Some(public_key)) = (&profile.private_key, &profile.public_key) {
+            if private_key.public_key() != *public_key {
+                return Err(CliError::CommandArgumentError(
+                    "public_key does not match private_key".to_string(),
+                ));
+            }
+        }
+
+        let mut config = if CliConfig::config_exists(ConfigSearchMode::CurrentDir) {
+            CliConfig::load(ConfigSearchMode::CurrentDir)?
+        } else {
+            CliConfig::default()
+        };
+        let profiles = config.profiles.get_or_insert_with(BTreeMap::new);
+        if profiles.contains_key(&self.profile_name) {
+            prompt_yes_with_override(
+                &format!(
+                    "Profile {} already exists, do you want to overwrite it?",
+                    self.profile_name
+                ),
+                self.prompt_options,
+            )?;
+        }
+
+        let summary = ProfileSummary::from(&profile);
+        profiles.insert(self.profile_name, profile);
+        config.save()?;
+        Ok(summary)
+    }
+}
+
 /// Shows the properties in the global config
 #[derive(Parser, Debug)]
 pub struct ShowGlobalConfig {}
@@ -16,6 +16,7 @@ use aptos_rest_client::aptos_api_types::U64;
 use aptos_rest_client::Transaction;
 use aptos_types::{
     account_address::AccountAddress,
+    account_config::CORE_CODE_ADDRESS,
     transaction::{Script, TransactionPayload},
 };
 use async_trait::async_trait;
@@ -110,7 +111,7 @@ impl CliCommand<ProposalSubmissionSummary> for SubmitProposal {
                 None,
             )
             .await?;
-        let txn_summary = TransactionSummary::from(&txn);
+        let txn_summary = self.txn_options.summarize(&txn);
         if let Transaction::UserTransaction(inner) = txn {
             // Find event with proposal id
             let proposal_id = if let Some(event) = inner.events.into_iter().find(|event| {
@@ -233,13 +234,76 @@ pub struct SubmitVote {
     pub(crate) pool_address_args: PoolAddressArgs,
 }
 
+/// Current votes on a proposal
+#[derive(Debug, Deserialize, Serialize)]
+struct VoteTally {
+    yes_votes: String,
+    no_votes: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct VoteSubmissionSummary {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tally: Option<VoteTally>,
+    #[serde(flatten)]
+    transaction: TransactionSummary,
+}
+
+impl SubmitVote {
+    /// Looks up the proposal in the governance `VotingForum` to get the current votes
+    async fn vote_tally(&self) -> CliTypedResult<VoteTally> {
+        const PROPOSAL_TYPE: &str = "0x1::governance_proposal::GovernanceProposal";
+
+        let client = self
+            .txn_options
+            .rest_options
+            .client(&self.txn_options.profile_options.profile)?;
+        let voting_forum = client
+            .get_account_resource(
+                CORE_CODE_ADDRESS,
+                &format!("0x1::voting::VotingForum<{}>", PROPOSAL_TYPE),
+            )
+            .await?
+            .into_inner()
+            .ok_or_else(|| CliError::UnexpectedError("Voting forum not found".to_string()))?
+            .data;
+        let proposals_handle = voting_forum["proposals"]["handle"]
+            .as_str()
+            .and_then(|handle| AccountAddress::from_hex_literal(handle).ok())
+            .ok_or_else(|| {
+                CliError::UnexpectedError("Unable to parse proposals table handle".to_string())
+            })?;
+
+        let proposal = client
+            .get_table_item(
+                proposals_handle,
+                "u64",
+                &format!("0x1::voting::Proposal<{}>", PROPOSAL_TYPE),
+                self.proposal_id.to_string(),
+            )
+            .await?
+            .into_inner();
+        let votes = |field: &str| {
+            proposal[field]
+                .as_str()
+                .map(|votes| votes.to_string())
+                .ok_or_else(|| CliError::UnexpectedError(format!("Unable to parse {}", field)))
+        };
+
+        Ok(VoteTally {
+            yes_votes: votes("yes_votes")?,
+            no_votes: votes("no_votes")?,
+        })
+    }
+}
+
 #[async_trait]
-impl CliCommand<TransactionSummary> for SubmitVote {
+impl CliCommand<VoteSubmissionSummary> for SubmitVote {
     fn command_name(&self) -> &'static str {
         "SubmitVote"
     }
 
-    async fn execute(mut self) -> CliTypedResult<TransactionSummary> {
+    async fn execute(mut self) -> CliTypedResult<VoteSubmissionSummary> {
         let (vote_str, vote) = match (self.yes, self.no) {
             (true, false) => ("Yes", true),
             (false, true) => ("No", false),
@@ -257,7 +321,8 @@ impl CliCommand<TransactionSummary> for SubmitVote {
             self.txn_options.prompt_options,
         )?;
 
-        self.txn_options
+        let transaction = self
+            .txn_options
             .submit_transaction(
                 aptos_stdlib::aptos_governance_vote(
                     self.pool_address_args.pool_address,
@@ -267,7 +332,23 @@ impl CliCommand<TransactionSummary> for SubmitVote {
                 None,
             )
             .await
-            .map(TransactionSummary::from)
+            .map(|txn| self.txn_options.summarize(&txn))?;
+
+        // The vote has already been submitted, so don't fail if the tally can't be read.  A
+        // simulated vote isn't counted, so there's no new tally to show
+        let tally = if self.txn_options.simulate {
+            None
+        } else {
+            match self.vote_tally().await {
+                Ok(tally) => Some(tally),
+                Err(err) => {
+                    eprintln!("Unable to fetch vote tally: {}", err);
+                    None
+                }
+            }
+        };
+
+        Ok(VoteSubmissionSummary { tally, transaction })
     }
 }
 
@@ -391,7 +472,7 @@ impl CliCommand<TransactionSummary> for ExecuteProposal {
         self.txn_options
             .submit_transaction(txn, None)
             .await
-            .map(TransactionSummary::from)
+            .map(|txn| self.txn_options.summarize(&txn))
     }
 }
 
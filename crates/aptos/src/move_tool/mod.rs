@@ -91,7 +91,13 @@ impl MoveTool {
             MoveTool::Download(tool) => tool.execute_serialized().await,
             MoveTool::List(tool) => tool.execute_serialized().await,
             MoveTool::Clean(tool) => tool.execute_serialized().await,
-            MoveTool::Run(tool) => tool.execute_serialized().await,
+            MoveTool::Run(tool) => {
+                if tool.sign_only {
+                    SignRunFunction(tool).execute_serialized().await
+                } else {
+                    tool.execute_serialized().await
+                }
+            }
             MoveTool::RunScript(tool) => tool.execute_serialized().await,
             MoveTool::Test(tool) => tool.execute_serialized().await,
             MoveTool::Prove(tool) => tool.execute_serialized().await,
@@ -517,7 +523,7 @@ impl CliCommand<TransactionSummary> for PublishPackage {
                     None,
                 )
                 .await
-                .map(TransactionSummary::from)
+                .map(|txn| txn_options.summarize(&txn))
         } else {
             // Send the compiled module and metadata using the code::publish_package_txn.
             let metadata = package.extract_metadata()?;
@@ -538,7 +544,7 @@ impl CliCommand<TransactionSummary> for PublishPackage {
             txn_options
                 .submit_transaction(payload, None)
                 .await
-                .map(TransactionSummary::from)
+                .map(|txn| txn_options.summarize(&txn))
         }
     }
 }
@@ -728,43 +734,69 @@ pub struct RunFunction {
     #[clap(long, multiple_values = true)]
     pub(crate) type_args: Vec<MoveType>,
 
+    /// Only sign the transaction, and output it as hex encoded BCS instead of submitting it
+    ///
+    /// Requires `--sequence-number`, `--chain-id`, and `--gas-unit-price`
+    #[clap(long)]
+    pub(crate) sign_only: bool,
+
     #[clap(flatten)]
     pub(crate) txn_options: TransactionOptions,
 }
 
-#[async_trait]
-impl CliCommand<TransactionSummary> for RunFunction {
-    fn command_name(&self) -> &'static str {
-        "RunFunction"
-    }
-
-    async fn execute(self) -> CliTypedResult<TransactionSummary> {
+impl RunFunction {
+    fn payload(&self) -> CliTypedResult<TransactionPayload> {
         let args: Vec<Vec<u8>> = self
             .args
-            .into_iter()
-            .map(|arg_with_type| arg_with_type.arg)
+            .iter()
+            .map(|arg_with_type| arg_with_type.arg.clone())
             .collect();
         let mut type_args: Vec<TypeTag> = Vec::new();
 
         // These TypeArgs are used for generics
-        for type_arg in self.type_args.into_iter() {
-            let type_tag = TypeTag::try_from(type_arg)
+        for type_arg in self.type_args.iter() {
+            let type_tag = TypeTag::try_from(type_arg.clone())
                 .map_err(|err| CliError::UnableToParse("--type-args", err.to_string()))?;
             type_args.push(type_tag)
         }
 
+        Ok(TransactionPayload::EntryFunction(EntryFunction::new(
+            self.function_id.module_id.clone(),
+            self.function_id.member_id.clone(),
+            type_args,
+            args,
+        )))
+    }
+}
+
+#[async_trait]
+impl CliCommand<TransactionSummary> for RunFunction {
+    fn command_name(&self) -> &'static str {
+        "RunFunction"
+    }
+
+    async fn execute(self) -> CliTypedResult<TransactionSummary> {
         self.txn_options
-            .submit_transaction(
-                TransactionPayload::EntryFunction(EntryFunction::new(
-                    self.function_id.module_id,
-                    self.function_id.member_id,
-                    type_args,
-                    args,
-                )),
-                None,
-            )
+            .submit_transaction(self.payload()?, None)
             .await
-            .map(TransactionSummary::from)
+            .map(|txn| self.txn_options.summarize(&txn))
+    }
+}
+
+/// Signs a Move function call without submitting it
+///
+/// Outputs the hex encoded BCS of the `SignedTransaction`
+pub struct SignRunFunction(pub RunFunction);
+
+#[async_trait]
+impl CliCommand<String> for SignRunFunction {
+    fn command_name(&self) -> &'static str {
+        "SignRunFunction"
+    }
+
+    async fn execute(self) -> CliTypedResult<String> {
+        let signed_transaction = self.0.txn_options.sign_only(self.0.payload()?)?;
+        Ok(hex::encode(bcs::to_bytes(&signed_transaction)?))
     }
 }
 
@@ -795,7 +827,7 @@ impl CliCommand<TransactionSummary> for RunScript {
                 None,
             )
             .await?;
-        Ok(TransactionSummary::from(&txn))
+        Ok(self.txn_options.summarize(&txn))
     }
 }
 
@@ -24,6 +24,8 @@ use aptos_config::config::NodeConfig;
 use aptos_crypto::{bls12381, x25519, ValidCryptoMaterialStringExt};
 use aptos_faucet::FaucetArgs;
 use aptos_genesis::config::{HostAndPort, OperatorConfiguration};
+use aptos_rest_client::aptos_api_types::HashValue;
+use aptos_rest_client::Transaction;
 use aptos_types::chain_id::ChainId;
 use aptos_types::network_address::NetworkAddress;
 use aptos_types::on_chain_config::{ConsensusScheme, ValidatorSet};
@@ -38,6 +40,7 @@ use backup_cli::utils::{
     ConcurrentDownloadsOpt, GlobalRestoreOpt, ReplayConcurrencyLevelOpt, RocksdbOpt,
 };
 use cached_packages::aptos_stdlib;
+use chrono::{TimeZone, Utc};
 use clap::Parser;
 use hex::FromHex;
 use rand::rngs::StdRng;
@@ -60,6 +63,7 @@ pub enum NodeTool {
     InitializeValidator(InitializeValidator),
     JoinValidatorSet(JoinValidatorSet),
     LeaveValidatorSet(LeaveValidatorSet),
+    ShowEpoch(ShowNodeStatus),
     ShowValidatorConfig(ShowValidatorConfig),
     ShowValidatorSet(ShowValidatorSet),
     ShowValidatorStake(ShowValidatorStake),
@@ -77,6 +81,7 @@ impl NodeTool {
             InitializeValidator(tool) => tool.execute_serialized().await,
             JoinValidatorSet(tool) => tool.execute_serialized().await,
             LeaveValidatorSet(tool) => tool.execute_serialized().await,
+            ShowEpoch(tool) => tool.execute_serialized().await,
             ShowValidatorSet(tool) => tool.execute_serialized().await,
             ShowValidatorStake(tool) => tool.execute_serialized().await,
             ShowValidatorConfig(tool) => tool.execute_serialized().await,
@@ -295,7 +300,7 @@ impl CliCommand<TransactionSummary> for InitializeValidator {
                 None,
             )
             .await
-            .map(|inner| inner.into())
+            .map(|txn| self.txn_options.summarize(&txn))
     }
 }
 
@@ -353,7 +358,7 @@ impl CliCommand<TransactionSummary> for JoinValidatorSet {
         self.txn_options
             .submit_transaction(aptos_stdlib::stake_join_validator_set(address), None)
             .await
-            .map(|inner| inner.into())
+            .map(|txn| self.txn_options.summarize(&txn))
     }
 }
 
@@ -380,7 +385,7 @@ impl CliCommand<TransactionSummary> for LeaveValidatorSet {
         self.txn_options
             .submit_transaction(aptos_stdlib::stake_leave_validator_set(address), None)
             .await
-            .map(|inner| inner.into())
+            .map(|txn| self.txn_options.summarize(&txn))
     }
 }
 
@@ -413,6 +418,73 @@ impl CliCommand<serde_json::Value> for ShowValidatorStake {
     }
 }
 
+/// Show the current epoch and round of the node
+///
+/// This is a quick check that the node is reachable and at the expected epoch
+#[derive(Parser)]
+pub struct ShowNodeStatus {
+    #[clap(flatten)]
+    pub(crate) profile_options: ProfileOptions,
+    #[clap(flatten)]
+    pub(crate) rest_options: RestOptions,
+}
+
+#[derive(Debug, Serialize)]
+pub struct NodeStatus {
+    pub epoch: u64,
+    pub round: u64,
+    pub version: u64,
+    pub block_height: u64,
+    pub block_hash: HashValue,
+    pub ledger_timestamp_usecs: u64,
+    /// Ledger timestamp in RFC 3339 format, in UTC
+    pub ledger_timestamp: String,
+}
+
+#[async_trait]
+impl CliCommand<NodeStatus> for ShowNodeStatus {
+    fn command_name(&self) -> &'static str {
+        "ShowNodeStatus"
+    }
+
+    async fn execute(self) -> CliTypedResult<NodeStatus> {
+        let client = self.rest_options.client(&self.profile_options.profile)?;
+        let index = client.get_index().await?.into_inner();
+        let block = client
+            .get_block_by_height(index.block_height.0, false)
+            .await?
+            .into_inner();
+
+        // The round is only available from the block's metadata transaction, genesis has none
+        let round = match client
+            .get_transaction_by_version(block.first_version.0)
+            .await?
+            .into_inner()
+        {
+            Transaction::BlockMetadataTransaction(txn) => txn.round.0,
+            _ => 0,
+        };
+
+        let ledger_timestamp_usecs = index.ledger_timestamp.0;
+        let ledger_timestamp = Utc
+            .timestamp(
+                (ledger_timestamp_usecs / 1_000_000) as i64,
+                ((ledger_timestamp_usecs % 1_000_000) * 1_000) as u32,
+            )
+            .to_rfc3339();
+
+        Ok(NodeStatus {
+            epoch: index.epoch.0,
+            round,
+            version: index.ledger_version.0,
+            block_height: block.block_height.0,
+            block_hash: block.block_hash,
+            ledger_timestamp_usecs,
+            ledger_timestamp,
+        })
+    }
+}
+
 /// Show validator details of the current validator
 #[derive(Parser)]
 pub struct ShowValidatorConfig {
@@ -808,7 +880,7 @@ impl CliCommand<TransactionSummary> for UpdateConsensusKey {
                 None,
             )
             .await
-            .map(|inner| inner.into())
+            .map(|txn| self.txn_options.summarize(&txn))
     }
 }
 
@@ -870,7 +942,7 @@ impl CliCommand<TransactionSummary> for UpdateValidatorNetworkAddresses {
                 None,
             )
             .await
-            .map(|inner| inner.into())
+            .map(|txn| self.txn_options.summarize(&txn))
     }
 }
 
@@ -1,13 +1,17 @@
 // Copyright (c) Aptos
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::common::types::{
-    CliCommand, CliResult, CliTypedResult, TransactionOptions, TransactionSummary,
+use crate::{
+    account::show_staking_info::StakingInfo,
+    common::types::{
+        CliCommand, CliError, CliResult, CliTypedResult, TransactionOptions, TransactionSummary,
+    },
 };
-use aptos_types::account_address::AccountAddress;
+use aptos_types::{account_address::AccountAddress, stake_pool::StakePool};
 use async_trait::async_trait;
 use cached_packages::aptos_stdlib;
 use clap::Parser;
+use serde::Serialize;
 
 /// Tool for manipulating stake
 ///
@@ -57,13 +61,16 @@ impl CliCommand<TransactionSummary> for AddStake {
     }
 
     async fn execute(mut self) -> CliTypedResult<TransactionSummary> {
-        self.txn_options
+        let summary = self
+            .txn_options
             .submit_transaction(
                 aptos_stdlib::stake_add_stake(self.amount),
                 Some(self.amount),
             )
             .await
-            .map(|inner| inner.into())
+            .map(|txn| self.txn_options.summarize(&txn))?;
+        show_stake_pool(&self.txn_options).await;
+        Ok(summary)
     }
 }
 
@@ -87,10 +94,13 @@ impl CliCommand<TransactionSummary> for UnlockStake {
     }
 
     async fn execute(mut self) -> CliTypedResult<TransactionSummary> {
-        self.txn_options
+        let summary = self
+            .txn_options
             .submit_transaction(aptos_stdlib::stake_unlock(self.amount), None)
             .await
-            .map(|inner| inner.into())
+            .map(|txn| self.txn_options.summarize(&txn))?;
+        show_stake_pool(&self.txn_options).await;
+        Ok(summary)
     }
 }
 
@@ -115,10 +125,13 @@ impl CliCommand<TransactionSummary> for WithdrawStake {
     }
 
     async fn execute(mut self) -> CliTypedResult<TransactionSummary> {
-        self.node_op_options
+        let summary = self
+            .node_op_options
             .submit_transaction(aptos_stdlib::stake_withdraw(self.amount), None)
             .await
-            .map(|inner| inner.into())
+            .map(|txn| self.node_op_options.summarize(&txn))?;
+        show_stake_pool(&self.node_op_options).await;
+        Ok(summary)
     }
 }
 
@@ -141,7 +154,7 @@ impl CliCommand<TransactionSummary> for IncreaseLockup {
         self.txn_options
             .submit_transaction(aptos_stdlib::stake_increase_lockup(), None)
             .await
-            .map(|inner| inner.into())
+            .map(|txn| self.txn_options.summarize(&txn))
     }
 }
 
@@ -189,7 +202,7 @@ impl CliCommand<TransactionSummary> for InitializeStakeOwner {
                 Some(self.initial_stake_amount),
             )
             .await
-            .map(|inner| inner.into())
+            .map(|txn| self.txn_options.summarize(&txn))
     }
 }
 
@@ -198,28 +211,65 @@ impl CliCommand<TransactionSummary> for InitializeStakeOwner {
 pub struct SetOperator {
     /// Account Address of delegated operator
     ///
-    /// If not specified, it will be the same as the owner
-    #[clap(long, parse(try_from_str=crate::common::types::load_account_arg))]
+    /// Must not be the zero address
+    #[clap(long, alias = "operator-addr", parse(try_from_str=crate::common::types::load_account_arg))]
     pub operator_address: AccountAddress,
 
     #[clap(flatten)]
     pub(crate) txn_options: TransactionOptions,
 }
 
+/// The outcome of setting the operator, along with whether it was confirmed on chain
+#[derive(Debug, Serialize)]
+pub struct SetOperatorSummary {
+    #[serde(flatten)]
+    pub transaction_summary: TransactionSummary,
+    /// Set when the stake pool's operator was checked after submitting
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub operator_verified: Option<bool>,
+}
+
 #[async_trait]
-impl CliCommand<TransactionSummary> for SetOperator {
+impl CliCommand<SetOperatorSummary> for SetOperator {
     fn command_name(&self) -> &'static str {
         "SetOperator"
     }
 
-    async fn execute(mut self) -> CliTypedResult<TransactionSummary> {
-        self.txn_options
+    async fn execute(mut self) -> CliTypedResult<SetOperatorSummary> {
+        if self.operator_address == AccountAddress::ZERO {
+            return Err(CliError::CommandArgumentError(
+                "--operator-address must not be the zero address".to_string(),
+            ));
+        }
+
+        let transaction_summary: TransactionSummary = self
+            .txn_options
             .submit_transaction(
                 aptos_stdlib::stake_set_operator(self.operator_address),
                 None,
             )
             .await
-            .map(|inner| inner.into())
+            .map(|txn| self.txn_options.summarize(&txn))?;
+
+        // Confirm the operator change landed on chain
+        let operator_verified = if self.txn_options.simulate {
+            None
+        } else {
+            let stake_pool = owner_stake_pool(&self.txn_options).await?;
+            if stake_pool.operator_address != self.operator_address {
+                return Err(CliError::UnexpectedError(format!(
+                    "Operator is {}, expected {}",
+                    stake_pool.operator_address, self.operator_address
+                )));
+            }
+            eprintln!("Operator is now {}", stake_pool.operator_address);
+            Some(true)
+        };
+
+        Ok(SetOperatorSummary {
+            transaction_summary,
+            operator_verified,
+        })
     }
 }
 
@@ -229,9 +279,13 @@ pub struct SetDelegatedVoter {
     /// Account Address of delegated voter
     ///
     /// If not specified, it will be the same as the owner
-    #[clap(long, parse(try_from_str=crate::common::types::load_account_arg))]
+    #[clap(long, alias = "voter-addr", parse(try_from_str=crate::common::types::load_account_arg))]
     pub voter_address: AccountAddress,
 
+    /// Check that the stake pool's delegated voter was updated after submitting
+    #[clap(long)]
+    pub verify: bool,
+
     #[clap(flatten)]
     pub(crate) txn_options: TransactionOptions,
 }
@@ -243,12 +297,54 @@ impl CliCommand<TransactionSummary> for SetDelegatedVoter {
     }
 
     async fn execute(mut self) -> CliTypedResult<TransactionSummary> {
-        self.txn_options
+        let summary: TransactionSummary = self
+            .txn_options
             .submit_transaction(
                 aptos_stdlib::stake_set_delegated_voter(self.voter_address),
                 None,
             )
             .await
-            .map(|inner| inner.into())
+            .map(|txn| self.txn_options.summarize(&txn))?;
+
+        if self.verify && !self.txn_options.simulate {
+            let stake_pool = owner_stake_pool(&self.txn_options).await?;
+            if stake_pool.delegated_voter != self.voter_address {
+                return Err(CliError::UnexpectedError(format!(
+                    "Delegated voter is {}, expected {}",
+                    stake_pool.delegated_voter, self.voter_address
+                )));
+            }
+            eprintln!("Delegated voter is now {}", stake_pool.delegated_voter);
+        }
+
+        Ok(summary)
+    }
+}
+
+/// Fetches the `0x1::stake::StakePool` of the transaction sender
+async fn owner_stake_pool(txn_options: &TransactionOptions) -> CliTypedResult<StakePool> {
+    let client = txn_options
+        .rest_options
+        .client(&txn_options.profile_options.profile)?;
+    Ok(client
+        .get_account_resource_bcs(txn_options.sender_address()?, "0x1::stake::StakePool")
+        .await?
+        .into_inner())
+}
+
+/// Prints the sender's stake pool, so the effect of a staking transaction can be seen
+async fn show_stake_pool(txn_options: &TransactionOptions) {
+    // A simulated transaction hasn't changed the stake pool
+    if txn_options.simulate {
+        return;
+    }
+
+    match owner_stake_pool(txn_options).await {
+        Ok(stake_pool) => {
+            if let Ok(staking_info) = serde_json::to_string_pretty(&StakingInfo::from(stake_pool)) {
+                eprintln!("Stake pool: {}", staking_info);
+            }
+        }
+        Err(err) => eprintln!("Unable to fetch stake pool: {}", err),
     }
 }
@@ -28,8 +28,8 @@ use crate::node::{
 };
 use crate::op::key::{ExtractPeer, GenerateKey, NetworkKeyInputOptions, SaveKey};
 use crate::stake::{
-    AddStake, IncreaseLockup, InitializeStakeOwner, SetDelegatedVoter, SetOperator, UnlockStake,
-    WithdrawStake,
+    AddStake, IncreaseLockup, InitializeStakeOwner, SetDelegatedVoter, SetOperator,
+    SetOperatorSummary, UnlockStake, WithdrawStake,
 };
 use crate::CliCommand;
 use aptos_config::config::Peer;
@@ -202,6 +202,11 @@ impl CliTestFramework {
             new_private_key: Some(new_private_key),
             save_to_profile: None,
             new_private_key_file: None,
+            scheduled_rotation_file: None,
+            simulate_only: false,
+            dry_run: false,
+            verify_event: true,
+            output_file: None,
         }
         .execute()
         .await
@@ -478,7 +483,7 @@ impl CliTestFramework {
         &self,
         owner_index: usize,
         operator_index: usize,
-    ) -> CliTypedResult<TransactionSummary> {
+    ) -> CliTypedResult<SetOperatorSummary> {
         SetOperator {
             txn_options: self.transaction_options(owner_index, None),
             operator_address: self.account_id(operator_index),
@@ -495,6 +500,7 @@ impl CliTestFramework {
         SetDelegatedVoter {
             txn_options: self.transaction_options(owner_index, None),
             voter_address: self.account_id(voter_index),
+            verify: false,
         }
         .execute()
         .await
@@ -769,6 +775,7 @@ impl CliTestFramework {
             function_id,
             args: parsed_args,
             type_args: parsed_type_args,
+            sign_only: false,
         }
         .execute()
         .await
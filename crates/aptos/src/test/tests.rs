@@ -1,8 +1,34 @@
 // Copyright (c) Aptos
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{CliResult, Tool};
+use crate::{
+    account::key_rotation::{
+        check_key_rotation_event, parse_rotation_proof, SignedRotationRequest,
+    },
+    common::{
+        types::{
+            account_address_from_public_key, AptAmount, CliError, CliTypedResult, GasOptions,
+            PrivateKeyInputOptions, RotationProofChallenge, TransactionOptions, DEFAULT_MAX_GAS,
+            OCTAS_PER_APT,
+        },
+        utils::parse_signed_transaction,
+    },
+    transaction::{decode_argument, is_signer},
+    CliResult, Tool,
+};
+use aptos_crypto::{ed25519::Ed25519PrivateKey, PrivateKey};
+use aptos_keygen::KeyGen;
+use aptos_rest_client::aptos_api_types::MoveType;
+use aptos_types::{
+    account_address::AccountAddress,
+    account_config::CORE_CODE_ADDRESS,
+    chain_id::ChainId,
+    transaction::{authenticator::AuthenticationKey, TransactionPayload},
+};
+use cached_packages::aptos_stdlib;
 use clap::Parser;
+use serde_json::json;
+use std::str::FromStr;
 
 /// In order to ensure that there aren't duplicate input arguments for untested CLI commands,
 /// we call help on every command to ensure it at least runs
@@ -13,12 +39,35 @@ async fn ensure_every_command_args_work() {
     assert_cmd_not_panic(&["aptos", "account"]).await;
     assert_cmd_not_panic(&["aptos", "account", "create", "--help"]).await;
     assert_cmd_not_panic(&["aptos", "account", "create-resource-account", "--help"]).await;
+    assert_cmd_not_panic(&[
+        "aptos",
+        "account",
+        "estimate-account-creation-gas",
+        "--help",
+    ])
+    .await;
     assert_cmd_not_panic(&["aptos", "account", "fund-with-faucet", "--help"]).await;
+    assert_cmd_not_panic(&["aptos", "account", "get-event-by-version", "--help"]).await;
+    assert_cmd_not_panic(&["aptos", "account", "get-validators", "--help"]).await;
     assert_cmd_not_panic(&["aptos", "account", "list", "--help"]).await;
+    assert_cmd_not_panic(&["aptos", "account", "list-profiles", "--help"]).await;
+    assert_cmd_not_panic(&["aptos", "account", "lookup-address", "--help"]).await;
+    assert_cmd_not_panic(&["aptos", "account", "offline-sign-rotation", "--help"]).await;
+    assert_cmd_not_panic(&["aptos", "account", "rotate-key", "--help"]).await;
+    assert_cmd_not_panic(&["aptos", "account", "rotate-key-batch", "--help"]).await;
+    assert_cmd_not_panic(&["aptos", "account", "query-table", "--help"]).await;
+    assert_cmd_not_panic(&["aptos", "account", "show-auth-key-history", "--help"]).await;
+    assert_cmd_not_panic(&["aptos", "account", "show-coin-info", "--help"]).await;
+    assert_cmd_not_panic(&["aptos", "account", "show-modules", "--help"]).await;
+    assert_cmd_not_panic(&["aptos", "account", "show-staking-info", "--help"]).await;
+    assert_cmd_not_panic(&["aptos", "account", "show-transaction", "--help"]).await;
+    assert_cmd_not_panic(&["aptos", "account", "show-transaction-by-version", "--help"]).await;
     assert_cmd_not_panic(&["aptos", "account", "transfer", "--help"]).await;
+    assert_cmd_not_panic(&["aptos", "account", "watch-balance", "--help"]).await;
 
     assert_cmd_not_panic(&["aptos", "config"]).await;
     assert_cmd_not_panic(&["aptos", "config", "generate-shell-completions", "--help"]).await;
+    assert_cmd_not_panic(&["aptos", "config", "import-profile-from-json", "--help"]).await;
     assert_cmd_not_panic(&["aptos", "config", "init", "--help"]).await;
     assert_cmd_not_panic(&["aptos", "config", "set-global-config", "--help"]).await;
     assert_cmd_not_panic(&["aptos", "config", "show-global-config"]).await;
@@ -64,6 +113,7 @@ async fn ensure_every_command_args_work() {
     assert_cmd_not_panic(&["aptos", "node", "join-validator-set", "--help"]).await;
     assert_cmd_not_panic(&["aptos", "node", "leave-validator-set", "--help"]).await;
     assert_cmd_not_panic(&["aptos", "node", "run-local-testnet", "--help"]).await;
+    assert_cmd_not_panic(&["aptos", "node", "show-epoch", "--help"]).await;
     assert_cmd_not_panic(&["aptos", "node", "show-validator-config", "--help"]).await;
     assert_cmd_not_panic(&["aptos", "node", "show-validator-set", "--help"]).await;
     assert_cmd_not_panic(&["aptos", "node", "show-validator-stake", "--help"]).await;
@@ -84,6 +134,10 @@ async fn ensure_every_command_args_work() {
     assert_cmd_not_panic(&["aptos", "stake", "set-operator", "--help"]).await;
     assert_cmd_not_panic(&["aptos", "stake", "unlock-stake", "--help"]).await;
     assert_cmd_not_panic(&["aptos", "stake", "withdraw-stake", "--help"]).await;
+
+    assert_cmd_not_panic(&["aptos", "transaction"]).await;
+    assert_cmd_not_panic(&["aptos", "transaction", "decode", "--help"]).await;
+    assert_cmd_not_panic(&["aptos", "transaction", "submit", "--help"]).await;
 }
 
 async fn assert_cmd_not_panic(args: &[&str]) {
@@ -107,7 +161,341 @@ async fn assert_cmd_not_panic(args: &[&str]) {
     }
 }
 
+/// Only a submitted rotation has a summary to write
+#[test]
+fn rotate_key_output_file_conflicts_with_unsubmitted_modes() {
+    for mode in ["--dry-run", "--simulate-only", "--simulate"] {
+        let err = Tool::try_parse_from(&[
+            "aptos",
+            "account",
+            "rotate-key",
+            "--new-private-key",
+            "0x1",
+            "--output-file",
+            "summary.json",
+            mode,
+        ])
+        .err()
+        .unwrap_or_else(|| panic!("--output-file should conflict with {}", mode));
+        assert_eq!(err.kind(), clap::ErrorKind::ArgumentConflict);
+    }
+}
+
 async fn run_cmd(args: &[&str]) -> CliResult {
     let tool: Tool = Tool::try_parse_from(args).map_err(|msg| msg.to_string())?;
     tool.execute().await
 }
+
+#[test]
+fn check_key_rotation_event_matches_new_auth_key() {
+    let mut keygen = KeyGen::from_seed([3; 32]);
+    let old_auth_key =
+        AuthenticationKey::ed25519(&keygen.generate_ed25519_private_key().public_key());
+    let new_auth_key =
+        AuthenticationKey::ed25519(&keygen.generate_ed25519_private_key().public_key());
+
+    // The data of a `0x1::account::KeyRotationEvent`, as returned by the API
+    let event_data = json!({
+        "old_authentication_key": format!("0x{}", hex::encode(old_auth_key.to_vec())),
+        "new_authentication_key": format!("0x{}", hex::encode(new_auth_key.to_vec())),
+    });
+
+    check_key_rotation_event(&event_data, new_auth_key).unwrap();
+    assert!(check_key_rotation_event(&event_data, old_auth_key).is_err());
+    assert!(check_key_rotation_event(&json!({}), new_auth_key).is_err());
+}
+
+#[test]
+fn apt_amount_from_octas() {
+    let cases = [
+        (0, "0.00000000"),
+        (1, "0.00000001"),
+        (OCTAS_PER_APT - 1, "0.99999999"),
+        (OCTAS_PER_APT, "1.00000000"),
+        (OCTAS_PER_APT + 1, "1.00000001"),
+        (123 * OCTAS_PER_APT + 4_500_000, "123.04500000"),
+        (u64::MAX, "184467440737.09551615"),
+    ];
+    for (octas, apt) in cases {
+        let amount = AptAmount::from(octas);
+        assert_eq!(amount.octas, octas);
+        assert_eq!(amount.apt, apt);
+    }
+}
+
+fn transfer_payload() -> TransactionPayload {
+    aptos_stdlib::aptos_coin_transfer(AccountAddress::from_hex_literal("0x1").unwrap(), 100)
+}
+
+fn sign_only_options(private_key: &Ed25519PrivateKey) -> TransactionOptions {
+    TransactionOptions {
+        private_key_options: PrivateKeyInputOptions::from_private_key(private_key).unwrap(),
+        sequence_number: Some(7),
+        chain_id: Some(ChainId::test()),
+        gas_options: GasOptions {
+            gas_unit_price: Some(100),
+            max_gas: None,
+        },
+        ..Default::default()
+    }
+}
+
+#[test]
+fn sign_only_signs_without_a_node() {
+    let private_key = KeyGen::from_seed([1; 32]).generate_ed25519_private_key();
+    let payload = transfer_payload();
+
+    let signed_transaction = sign_only_options(&private_key)
+        .sign_only(payload.clone())
+        .unwrap();
+
+    assert_eq!(
+        signed_transaction.sender(),
+        account_address_from_public_key(&private_key.public_key())
+    );
+    assert_eq!(signed_transaction.sequence_number(), 7);
+    assert_eq!(signed_transaction.chain_id(), ChainId::test());
+    assert_eq!(signed_transaction.gas_unit_price(), 100);
+    assert_eq!(signed_transaction.max_gas_amount(), DEFAULT_MAX_GAS);
+    assert_eq!(signed_transaction.payload(), &payload);
+    signed_transaction.check_signature().unwrap();
+}
+
+#[test]
+fn sign_only_requires_offline_options() {
+    let private_key = KeyGen::from_seed([1; 32]).generate_ed25519_private_key();
+    let payload = transfer_payload();
+
+    let mut options = sign_only_options(&private_key);
+    options.sequence_number = None;
+    assert!(matches!(
+        options.sign_only(payload.clone()),
+        Err(CliError::CommandArgumentError(_))
+    ));
+
+    let mut options = sign_only_options(&private_key);
+    options.chain_id = None;
+    assert!(matches!(
+        options.sign_only(payload.clone()),
+        Err(CliError::CommandArgumentError(_))
+    ));
+
+    let mut options = sign_only_options(&private_key);
+    options.gas_options.gas_unit_price = None;
+    assert!(matches!(
+        options.sign_only(payload),
+        Err(CliError::CommandArgumentError(_))
+    ));
+}
+
+#[test]
+fn parse_signed_transaction_checks_signature() {
+    let private_key = KeyGen::from_seed([1; 32]).generate_ed25519_private_key();
+    let signed_transaction = sign_only_options(&private_key)
+        .sign_only(transfer_payload())
+        .unwrap();
+    let mut bytes = bcs::to_bytes(&signed_transaction).unwrap();
+
+    let parsed = parse_signed_transaction(&format!("0x{}\n", hex::encode(&bytes))).unwrap();
+    assert_eq!(parsed, signed_transaction);
+
+    // Changing the sender invalidates the signature
+    bytes[0] ^= 1;
+    assert!(matches!(
+        parse_signed_transaction(&hex::encode(&bytes)),
+        Err(CliError::CommandArgumentError(_))
+    ));
+    assert!(parse_signed_transaction("not hex").is_err());
+}
+
+fn test_rotation_proof(
+    current_private_key: &Ed25519PrivateKey,
+    new_private_key: &Ed25519PrivateKey,
+) -> RotationProofChallenge {
+    let current_auth_key = AuthenticationKey::ed25519(&current_private_key.public_key());
+    RotationProofChallenge {
+        account_address: CORE_CODE_ADDRESS,
+        module_name: "account".to_string(),
+        struct_name: "RotationProofChallenge".to_string(),
+        sequence_number: 3,
+        originator: current_auth_key.derived_address(),
+        current_auth_key: AccountAddress::from_bytes(&current_auth_key).unwrap(),
+        new_public_key: new_private_key.public_key().to_bytes().to_vec(),
+    }
+}
+
+fn verify_rotation_request(request: &SignedRotationRequest) -> CliTypedResult<()> {
+    request.rotation_proof_challenge.verify(
+        &request.current_public_key,
+        &request.rotation_proof_signed_by_current_private_key,
+        &request.rotation_proof_signed_by_new_private_key,
+    )
+}
+
+#[test]
+fn signed_rotation_request_verifies() {
+    let mut keygen = KeyGen::from_seed([2; 32]);
+    let current_private_key = keygen.generate_ed25519_private_key();
+    let new_private_key = keygen.generate_ed25519_private_key();
+
+    let request = SignedRotationRequest::new(
+        test_rotation_proof(&current_private_key, &new_private_key),
+        &current_private_key,
+        &new_private_key,
+    )
+    .unwrap();
+
+    verify_rotation_request(&request).unwrap();
+    assert_eq!(request.current_public_key, current_private_key.public_key());
+    assert_eq!(
+        request.new_auth_key().unwrap(),
+        AuthenticationKey::ed25519(&new_private_key.public_key())
+    );
+}
+
+#[test]
+fn tampered_rotation_request_fails_verification() {
+    let mut keygen = KeyGen::from_seed([2; 32]);
+    let current_private_key = keygen.generate_ed25519_private_key();
+    let new_private_key = keygen.generate_ed25519_private_key();
+    let other_private_key = keygen.generate_ed25519_private_key();
+    let request = || {
+        SignedRotationRequest::new(
+            test_rotation_proof(&current_private_key, &new_private_key),
+            &current_private_key,
+            &new_private_key,
+        )
+        .unwrap()
+    };
+
+    // The challenge changed after signing
+    let mut tampered = request();
+    tampered.rotation_proof_challenge.sequence_number += 1;
+    assert!(verify_rotation_request(&tampered).is_err());
+
+    // The new key in the challenge isn't the one that signed it
+    let mut tampered = request();
+    tampered.rotation_proof_challenge.new_public_key =
+        other_private_key.public_key().to_bytes().to_vec();
+    assert!(verify_rotation_request(&tampered).is_err());
+
+    // The signatures are swapped
+    let mut tampered = request();
+    std::mem::swap(
+        &mut tampered.rotation_proof_signed_by_current_private_key,
+        &mut tampered.rotation_proof_signed_by_new_private_key,
+    );
+    assert!(verify_rotation_request(&tampered).is_err());
+
+    // Signed by a key other than the current one
+    let mut tampered = SignedRotationRequest::new(
+        test_rotation_proof(&current_private_key, &new_private_key),
+        &other_private_key,
+        &new_private_key,
+    )
+    .unwrap();
+    tampered.current_public_key = current_private_key.public_key();
+    assert!(verify_rotation_request(&tampered).is_err());
+}
+
+#[test]
+fn new_auth_key_rejects_invalid_public_key() {
+    let mut keygen = KeyGen::from_seed([2; 32]);
+    let current_private_key = keygen.generate_ed25519_private_key();
+    let new_private_key = keygen.generate_ed25519_private_key();
+
+    let mut request = SignedRotationRequest::new(
+        test_rotation_proof(&current_private_key, &new_private_key),
+        &current_private_key,
+        &new_private_key,
+    )
+    .unwrap();
+    request.rotation_proof_challenge.new_public_key = vec![0; 3];
+    assert!(request.new_auth_key().is_err());
+}
+
+#[test]
+fn parse_rotation_proof_accepts_hex_and_bcs() {
+    let mut keygen = KeyGen::from_seed([2; 32]);
+    let current_private_key = keygen.generate_ed25519_private_key();
+    let new_private_key = keygen.generate_ed25519_private_key();
+    let bytes =
+        bcs::to_bytes(&test_rotation_proof(&current_private_key, &new_private_key)).unwrap();
+
+    // Raw BCS, and the hex output by `rotate-key --dry-run`, with or without a prefix
+    let encodings = [
+        bytes.clone(),
+        hex::encode(&bytes).into_bytes(),
+        format!("0x{}\n", hex::encode(&bytes)).into_bytes(),
+    ];
+    for encoding in encodings {
+        let parsed = parse_rotation_proof(&encoding).unwrap();
+        assert_eq!(bcs::to_bytes(&parsed).unwrap(), bytes);
+    }
+
+    assert!(parse_rotation_proof(b"0xabcd").is_err());
+}
+
+fn move_type(str: &str) -> MoveType {
+    MoveType::from_str(str).unwrap()
+}
+
+#[test]
+fn decode_argument_decodes_supported_types() {
+    let address = AccountAddress::from_hex_literal("0xcafe").unwrap();
+    let cases = [
+        ("bool", bcs::to_bytes(&true).unwrap(), json!(true)),
+        ("u8", bcs::to_bytes(&7u8).unwrap(), json!(7)),
+        (
+            "u64",
+            bcs::to_bytes(&u64::MAX).unwrap(),
+            json!(u64::MAX.to_string()),
+        ),
+        (
+            "u128",
+            bcs::to_bytes(&u128::MAX).unwrap(),
+            json!(u128::MAX.to_string()),
+        ),
+        ("address", bcs::to_bytes(&address).unwrap(), json!("0xcafe")),
+        (
+            "vector<u8>",
+            bcs::to_bytes(&vec![1u8, 2]).unwrap(),
+            json!("0x0102"),
+        ),
+        (
+            "0x1::string::String",
+            bcs::to_bytes("hello").unwrap(),
+            json!("hello"),
+        ),
+    ];
+    for (type_str, bytes, expected) in cases {
+        assert_eq!(
+            decode_argument(&move_type(type_str), &bytes),
+            expected,
+            "{}",
+            type_str
+        );
+    }
+}
+
+#[test]
+fn decode_argument_falls_back_to_hex() {
+    // Types without a decoder
+    let bytes = bcs::to_bytes(&vec![1u64]).unwrap();
+    assert_eq!(
+        decode_argument(&move_type("vector<u64>"), &bytes),
+        json!(format!("0x{}", hex::encode(&bytes)))
+    );
+
+    // Bytes that don't match the type
+    assert_eq!(decode_argument(&move_type("u64"), &[1, 2]), json!("0x0102"));
+    assert_eq!(decode_argument(&move_type("bool"), &[2]), json!("0x02"));
+}
+
+#[test]
+fn is_signer_matches_signer_references() {
+    assert!(is_signer(&move_type("signer")));
+    assert!(is_signer(&move_type("&signer")));
+    assert!(!is_signer(&move_type("address")));
+    assert!(!is_signer(&move_type("vector<signer>")));
+}
@@ -0,0 +1,196 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::common::{
+    types::{
+        CliCommand, CliError, CliResult, CliTypedResult, ProfileOptions, RestOptions,
+        TransactionSummary,
+    },
+    utils::{parse_signed_transaction, read_from_file},
+};
+use aptos_rest_client::aptos_api_types::MoveType;
+use aptos_types::{
+    account_address::AccountAddress,
+    transaction::{SignedTransaction, TransactionPayload},
+};
+use async_trait::async_trait;
+use clap::{Parser, Subcommand};
+use serde::Serialize;
+use serde_json::json;
+use std::path::PathBuf;
+
+/// Tool for interacting with transactions
+///
+/// This tool is used to submit transactions that were signed elsewhere,
+/// e.g. with `aptos move run --sign-only`, and to inspect transaction payloads.
+#[derive(Subcommand)]
+pub enum TransactionTool {
+    Decode(DecodeTransaction),
+    Submit(SubmitSignedTransaction),
+}
+
+impl TransactionTool {
+    pub async fn execute(self) -> CliResult {
+        match self {
+            TransactionTool::Decode(tool) => tool.execute_serialized().await,
+            TransactionTool::Submit(tool) => tool.execute_serialized().await,
+        }
+    }
+}
+
+/// Submit a transaction that has already been signed
+///
+/// The file must contain the hex encoded BCS of a `SignedTransaction`.
+#[derive(Debug, Parser)]
+pub struct SubmitSignedTransaction {
+    /// File containing the hex encoded signed transaction
+    #[clap(long, parse(from_os_str))]
+    pub(crate) signed_txn_file: PathBuf,
+
+    #[clap(flatten)]
+    pub(crate) rest_options: RestOptions,
+    #[clap(flatten)]
+    pub(crate) profile_options: ProfileOptions,
+}
+
+impl SubmitSignedTransaction {
+    fn signed_transaction(&self) -> CliTypedResult<SignedTransaction> {
+        let bytes = read_from_file(self.signed_txn_file.as_path())?;
+        let hex_string = String::from_utf8(bytes)
+            .map_err(|err| CliError::UnableToParse("--signed-txn-file", err.to_string()))?;
+        parse_signed_transaction(&hex_string)
+    }
+}
+
+#[async_trait]
+impl CliCommand<TransactionSummary> for SubmitSignedTransaction {
+    fn command_name(&self) -> &'static str {
+        "SubmitSignedTransaction"
+    }
+
+    async fn execute(self) -> CliTypedResult<TransactionSummary> {
+        let signed_transaction = self.signed_transaction()?;
+        let client = self.rest_options.client(&self.profile_options.profile)?;
+        client
+            .submit_and_wait(&signed_transaction)
+            .await
+            .map(|response| TransactionSummary::from(response.into_inner()))
+            .map_err(|err| CliError::ApiError(err.to_string()))
+    }
+}
+
+/// Decode a BCS encoded entry function payload
+///
+/// The arguments are decoded using the function's ABI, which is fetched from the node.
+#[derive(Debug, Parser)]
+pub struct DecodeTransaction {
+    /// Hex encoded BCS of the `TransactionPayload`
+    #[clap(long)]
+    pub(crate) payload: String,
+
+    #[clap(flatten)]
+    pub(crate) rest_options: RestOptions,
+    #[clap(flatten)]
+    pub(crate) profile_options: ProfileOptions,
+}
+
+/// A human readable entry function payload
+#[derive(Debug, Serialize)]
+pub struct DecodedTransaction {
+    pub module_address: AccountAddress,
+    pub function: String,
+    pub type_arguments: Vec<String>,
+    pub arguments: Vec<serde_json::Value>,
+}
+
+#[async_trait]
+impl CliCommand<DecodedTransaction> for DecodeTransaction {
+    fn command_name(&self) -> &'static str {
+        "DecodeTransaction"
+    }
+
+    async fn execute(self) -> CliTypedResult<DecodedTransaction> {
+        let payload = self.payload.trim();
+        let bytes = hex::decode(payload.strip_prefix("0x").unwrap_or(payload))
+            .map_err(|err| CliError::UnableToParse("--payload", err.to_string()))?;
+        let entry_function = match bcs::from_bytes(&bytes)? {
+            TransactionPayload::EntryFunction(entry_function) => entry_function,
+            _ => {
+                return Err(CliError::CommandArgumentError(
+                    "Only entry function payloads can be decoded".to_string(),
+                ))
+            }
+        };
+        let module = entry_function.module();
+
+        let client = self.rest_options.client(&self.profile_options.profile)?;
+        let abi = client
+            .get_account_module(*module.address(), module.name().as_str())
+            .await?
+            .into_inner()
+            .try_parse_abi()?
+            .abi
+            .ok_or_else(|| CliError::UnexpectedError("Unable to parse module ABI".to_string()))?;
+        let function = abi
+            .exposed_functions
+            .iter()
+            .find(|function| function.name.as_str() == entry_function.function().as_str())
+            .ok_or_else(|| {
+                CliError::CommandArgumentError(format!(
+                    "Function {} not found in module {}",
+                    entry_function.function(),
+                    module.short_str_lossless()
+                ))
+            })?;
+
+        // Signers are not passed as arguments in the payload
+        let param_types = function.params.iter().filter(|param| !is_signer(param));
+
+        Ok(DecodedTransaction {
+            module_address: *module.address(),
+            function: format!(
+                "{}::{}",
+                module.short_str_lossless(),
+                entry_function.function()
+            ),
+            type_arguments: entry_function
+                .ty_args()
+                .iter()
+                .map(|type_tag| type_tag.to_string())
+                .collect(),
+            arguments: param_types
+                .zip(entry_function.args())
+                .map(|(param_type, arg)| decode_argument(param_type, arg))
+                .collect(),
+        })
+    }
+}
+
+pub(crate) fn is_signer(move_type: &MoveType) -> bool {
+    match move_type {
+        MoveType::Signer => true,
+        MoveType::Reference { to, .. } => is_signer(to),
+        _ => false,
+    }
+}
+
+/// Decodes an argument into the JSON format used by the REST API, falling back to hex
+pub(crate) fn decode_argument(move_type: &MoveType, bytes: &[u8]) -> serde_json::Value {
+    let decoded = match move_type {
+        MoveType::Bool => bcs::from_bytes::<bool>(bytes).map(|value| json!(value)),
+        MoveType::U8 => bcs::from_bytes::<u8>(bytes).map(|value| json!(value)),
+        MoveType::U64 => bcs::from_bytes::<u64>(bytes).map(|value| json!(value.to_string())),
+        MoveType::U128 => bcs::from_bytes::<u128>(bytes).map(|value| json!(value.to_string())),
+        MoveType::Address => {
+            bcs::from_bytes::<AccountAddress>(bytes).map(|value| json!(value.to_hex_literal()))
+        }
+        MoveType::Vector { items } if **items == MoveType::U8 => bcs::from_bytes::<Vec<u8>>(bytes)
+            .map(|value| json!(format!("0x{}", hex::encode(value)))),
+        MoveType::Struct(_) if move_type.to_string() == "0x1::string::String" => {
+            bcs::from_bytes::<String>(bytes).map(|value| json!(value))
+        }
+        _ => return json!(format!("0x{}", hex::encode(bytes))),
+    };
+
+    decoded.unwrap_or_else(|_| json!(format!("0x{}", hex::encode(bytes))))
+}
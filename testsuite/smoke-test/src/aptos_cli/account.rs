@@ -6,11 +6,13 @@ use aptos::account::create::DEFAULT_FUNDED_COINS;
 use aptos::common::types::GasOptions;
 use aptos_crypto::PrivateKey;
 use aptos_keygen::KeyGen;
+use aptos_sdk::types::LocalAccount;
 use aptos_types::{
     account_address::AccountAddress, account_config::CORE_CODE_ADDRESS,
     transaction::authenticator::AuthenticationKey,
 };
-use forge::NodeExt;
+use cached_packages::aptos_stdlib;
+use forge::{NodeExt, Swarm};
 
 #[tokio::test]
 async fn test_account_flow() {
@@ -91,17 +93,19 @@ async fn test_account_flow() {
 
 #[tokio::test]
 async fn test_account_key_rotation() {
-    let (swarm, cli, _faucet) = SwarmBuilder::new_local(1)
+    let (mut swarm, cli, _faucet) = SwarmBuilder::new_local(1)
         .with_aptos()
-        .build_with_cli(1)
+        .build_with_cli(2)
         .await;
 
     let mut keygen = KeyGen::from_os_rng();
     let new_private_key = keygen.generate_ed25519_private_key();
 
-    cli.rotate_key(0, hex::encode(new_private_key.to_bytes()))
+    let rotate_summary = cli
+        .rotate_key(0, hex::encode(new_private_key.to_bytes()))
         .await
         .unwrap();
+    assert_eq!(rotate_summary.event_verified, Some(true));
 
     let rest_client = swarm.validators().next().unwrap().rest_client();
 
@@ -137,4 +141,27 @@ async fn test_account_key_rotation() {
         .unwrap(),
         cli.account_id(0)
     );
+
+    // The account's authentication key now belongs to the new key
+    let account = rest_client
+        .get_account(cli.account_id(0))
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(
+        account.authentication_key,
+        AuthenticationKey::ed25519(&new_private_key.public_key())
+    );
+
+    // The old key can no longer sign for the account
+    cli.transfer_coins(0, 1, 5, None).await.unwrap_err();
+
+    // But the new key can
+    let transaction_factory = swarm.chain_info().transaction_factory();
+    let mut account =
+        LocalAccount::new(cli.account_id(0), new_private_key, account.sequence_number);
+    let txn = account.sign_with_transaction_builder(
+        transaction_factory.payload(aptos_stdlib::aptos_coin_transfer(cli.account_id(1), 5)),
+    );
+    rest_client.submit_and_wait(&txn).await.unwrap();
 }
@@ -26,6 +26,13 @@ pub fn from_identity_public_key(identity_public_key: x25519::PublicKey) -> Accou
     AccountAddress::new(array)
 }
 
+/// Derives the address of a resource account, matching `0x1::account::create_resource_address`
+pub fn create_resource_address(address: AccountAddress, seed: &[u8]) -> AccountAddress {
+    let mut input = bcs::to_bytes(&address).unwrap();
+    input.extend(seed);
+    AccountAddress::new(*HashValue::sha3_256_of(&input))
+}
+
 // Define the Hasher used for hashing AccountAddress types. In order to properly use the
 // CryptoHasher derive macro we need to have this in its own module so that it doesn't conflict
 // with the imported `AccountAddress` from move-core-types. It needs to have the same name since
@@ -49,7 +56,7 @@ impl HashAccountAddress for AccountAddress {
 
 #[cfg(test)]
 mod test {
-    use super::{AccountAddress, HashAccountAddress};
+    use super::{create_resource_address, AccountAddress, HashAccountAddress};
     use aptos_crypto::hash::HashValue;
     use hex::FromHex;
 
@@ -67,4 +74,16 @@ mod test {
         hash.copy_from_slice(bytes);
         assert_eq!(address.hash(), HashValue::new(hash));
     }
+
+    #[test]
+    fn resource_address() {
+        let address = AccountAddress::from_hex_literal("0x1").unwrap();
+        let seed = bcs::to_bytes("test").unwrap();
+
+        let expected = AccountAddress::from_hex_literal(
+            "0xa52fa7c41cc55cc2c26dbca944f9607fb784df5e7fcf87c8fdd0ab252b3ca4cb",
+        )
+        .unwrap();
+        assert_eq!(create_resource_address(address, &seed), expected);
+    }
 }